@@ -0,0 +1,147 @@
+use crate::{
+    structures::{Card, CardVec, Declaration, Player},
+    GameState, GameValueBreakdown, RamschResult, Skat,
+};
+
+/// Compact description of the changes between two [`Skat`] states.
+///
+/// Only the parts which changed are stored.
+/// Each part replaces the corresponding part of the previous state when
+/// applied.
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub(crate) struct StateDelta {
+    hands: Vec<(Player, CardVec)>,
+    skat: Option<CardVec>,
+    trick: Option<Vec<Card>>,
+    played: Vec<(Player, Vec<Card>)>,
+    bid: Option<u16>,
+    declarer: Option<Player>,
+    declaration: Option<Declaration>,
+    spitze: Option<bool>,
+    pushes: Option<u8>,
+    picker: Option<Option<Player>>,
+    forehand_seat: Option<u8>,
+    breakdown: Option<Option<GameValueBreakdown>>,
+    ramsch_result: Option<Option<RamschResult>>,
+    state: Option<GameState>,
+}
+
+impl StateDelta {
+    /// Returns `true` if nothing changed.
+    pub(crate) fn is_empty(&self) -> bool {
+        *self == Self::default()
+    }
+}
+
+impl Skat {
+    /// Calculate the changes needed to get from `previous` to `self`.
+    ///
+    /// The options of both states are assumed to be the same.
+    pub(crate) fn diff(&self, previous: &Skat) -> StateDelta {
+        let (cards, prev_cards) = (&self.cards, &previous.cards);
+        StateDelta {
+            hands: Player::all()
+                .into_iter()
+                .filter(|&p| cards[p] != prev_cards[p])
+                .map(|p| (p, cards[p].clone()))
+                .collect(),
+            skat: changed(&cards.skat, &prev_cards.skat).cloned(),
+            trick: changed(&cards.trick, &prev_cards.trick).cloned(),
+            played: Player::all()
+                .into_iter()
+                .filter(|&p| cards.played[p as usize] != prev_cards.played[p as usize])
+                .map(|p| (p, cards.played[p as usize].clone()))
+                .collect(),
+            bid: changed(&self.bid, &previous.bid).copied(),
+            declarer: changed(&self.declarer, &previous.declarer).copied(),
+            declaration: changed(&self.declaration, &previous.declaration).copied(),
+            spitze: changed(&self.spitze, &previous.spitze).copied(),
+            pushes: changed(&self.pushes, &previous.pushes).copied(),
+            picker: changed(&self.picker, &previous.picker).copied(),
+            forehand_seat: changed(&self.forehand_seat, &previous.forehand_seat).copied(),
+            breakdown: changed(&self.breakdown, &previous.breakdown).copied(),
+            ramsch_result: changed(&self.ramsch_result, &previous.ramsch_result).cloned(),
+            state: changed(&self.state, &previous.state).cloned(),
+        }
+    }
+
+    /// Apply the `delta` to `self` which must be the state it was calculated
+    /// from.
+    pub(crate) fn apply_delta(&mut self, delta: &StateDelta) {
+        for (player, hand) in &delta.hands {
+            self.cards[*player] = hand.clone();
+        }
+        if let Some(skat) = &delta.skat {
+            self.cards.skat = skat.clone();
+        }
+        if let Some(trick) = &delta.trick {
+            self.cards.trick = trick.clone();
+        }
+        for (player, played) in &delta.played {
+            self.cards.played[*player as usize] = played.clone();
+        }
+        if let Some(bid) = delta.bid {
+            self.bid = bid;
+        }
+        if let Some(declarer) = delta.declarer {
+            self.declarer = declarer;
+        }
+        if let Some(declaration) = delta.declaration {
+            self.declaration = declaration;
+        }
+        if let Some(spitze) = delta.spitze {
+            self.spitze = spitze;
+        }
+        if let Some(pushes) = delta.pushes {
+            self.pushes = pushes;
+        }
+        if let Some(picker) = delta.picker {
+            self.picker = picker;
+        }
+        if let Some(forehand_seat) = delta.forehand_seat {
+            self.forehand_seat = forehand_seat;
+        }
+        if let Some(breakdown) = delta.breakdown {
+            self.breakdown = breakdown;
+        }
+        if let Some(ramsch_result) = &delta.ramsch_result {
+            self.ramsch_result = ramsch_result.clone();
+        }
+        if let Some(state) = &delta.state {
+            self.state = state.clone();
+        }
+    }
+}
+
+/// Returns `new` if it differs from `old`.
+fn changed<'a, T: PartialEq>(new: &'a T, old: &T) -> Option<&'a T> {
+    (new != old).then_some(new)
+}
+
+#[cfg(test)]
+mod tests {
+    use mirabel::game::PLAYER_RAND;
+
+    use super::*;
+
+    #[test]
+    fn diff_and_apply() {
+        let previous = Skat::default();
+        assert!(previous.diff(&previous).is_empty());
+
+        let mut game = previous.clone();
+        for card in Card::all() {
+            game.play(PLAYER_RAND, card.into()).unwrap();
+        }
+        let delta = game.diff(&previous);
+        assert!(!delta.is_empty());
+        assert_eq!(delta.hands.len(), Player::COUNT);
+        assert_eq!(delta.bid, None);
+
+        let mut applied = previous.clone();
+        applied.apply_delta(&delta);
+        assert_eq!(applied.cards, game.cards);
+        assert_eq!(applied.state, game.state);
+        assert!(game.diff(&applied).is_empty());
+    }
+}
@@ -0,0 +1,258 @@
+use std::fmt::{self, Display};
+
+use mirabel::error::{Error, ErrorCode};
+use nom::error::{VerboseError, VerboseErrorKind};
+
+/// Result of the internal steps which fail with a [`SkatError`].
+pub(crate) type SkatResult<T> = std::result::Result<T, SkatError>;
+
+/// All errors which can occur in this game.
+///
+/// These convert into a mirabel [`Error`] with the matching [`ErrorCode`].
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub(crate) enum SkatError {
+    /// Only [`mirabel::game::PLAYER_RAND`] can deal cards.
+    DealerNotRandom,
+    CardAlreadyDealt,
+    /// Not every card is dealt exactly once.
+    InvalidDeal,
+    /// All cards have already been dealt.
+    OverDealt,
+    /// The player id does not belong to one of the three seats.
+    NoSeat,
+    /// The player is not at turn.
+    NotYourTurn,
+    InvalidBiddingResponse,
+    /// The bid contradicts the state of the bidding phase.
+    InconsistentBidding,
+    /// This requires [`crate::GameState::Playing`].
+    NotPlaying,
+    /// No moves are possible in [`crate::GameState::Finished`].
+    GameOver,
+    InvalidBid,
+    /// Only the next valid bid value is allowed.
+    NotNextBid,
+    /// The bid could not be parsed as a number.
+    InvalidBidNumber(String),
+    InvalidSkatDecision,
+    /// Only [`mirabel::game::PLAYER_RAND`] can pick up Skat cards.
+    PickerNotRandom,
+    NoSkatCard,
+    WrongSkatCard,
+    /// The card is already at another place.
+    CardElsewhere,
+    EmptyHand,
+    CardNotInHand,
+    /// Cannot take the card from the player.
+    CannotTake,
+    /// Schneider, Schwarz, and Ouvert require a Hand game.
+    LevelRequiresHand,
+    HandAfterPicking,
+    HandRequired,
+    Overbidding,
+    NotOverbidden,
+    SpitzeDisabled,
+    SpitzeAnnounced,
+    SpitzeInNull,
+    /// The declarer does not hold the lowest trump for Spitze.
+    SpitzeCardMissing,
+    /// Only the declarer can reveal their cards.
+    NotDeclarer,
+    /// The card with this index cannot be revealed as it does not exist.
+    RevealMissing(usize),
+    WrongRevealCard,
+    /// The declarer's hand still contains hidden cards after revealing.
+    UnrevealedHand,
+    /// The declarer would not hold a full hand after putting back the Skat.
+    HandSizeMismatch,
+    /// The hand contains hidden cards.
+    HandHidden,
+    CardNotAllowed,
+    CardValueTooHigh,
+    SuitTooHigh,
+    InvalidDeclarationMove,
+    /// _Ramsch_ is only played when everybody passed.
+    RamschDeclared,
+    /// Kontra or Re cannot be announced now.
+    InvalidAnnouncement,
+    InvalidNormalMode,
+    InvalidGameLevel,
+    /// No move has been recorded which could be taken back.
+    #[cfg(feature = "history")]
+    NothingToUndo,
+    /// The moves cannot be replayed without the initial state.
+    #[cfg(feature = "history")]
+    NoInitialState,
+    /// The move with this index of a transcript is invalid for the given
+    /// reason.
+    TranscriptMove(usize, Box<SkatError>),
+    /// Failed to parse the named object with the given nom error trace.
+    Parse(&'static str, String),
+    UnknownOption(String),
+    /// The option expects a boolean value.
+    InvalidBoolOption(String),
+    /// Failed to parse the options with the given nom error trace.
+    ParseOptions(String),
+}
+
+impl SkatError {
+    /// Returns the [`ErrorCode`] for `self`.
+    pub(crate) fn code(&self) -> ErrorCode {
+        match self {
+            Self::DealerNotRandom
+            | Self::NoSeat
+            | Self::NotYourTurn
+            | Self::PickerNotRandom
+            | Self::NotDeclarer => ErrorCode::InvalidPlayer,
+            Self::NoSkatCard
+            | Self::OverDealt
+            | Self::EmptyHand
+            | Self::GameOver
+            | Self::UnrevealedHand
+            | Self::HandSizeMismatch
+            | Self::NotPlaying
+            | Self::HandHidden
+            | Self::RevealMissing(_) => ErrorCode::InvalidState,
+            #[cfg(feature = "history")]
+            Self::NothingToUndo | Self::NoInitialState => ErrorCode::InvalidState,
+            Self::InvalidDeal
+            | Self::InconsistentBidding
+            | Self::InvalidBidNumber(_)
+            | Self::InvalidSkatDecision
+            | Self::Parse(_, _) => ErrorCode::InvalidInput,
+            Self::TranscriptMove(_, _) => ErrorCode::InvalidInput,
+            Self::UnknownOption(_) | Self::InvalidBoolOption(_) | Self::ParseOptions(_) => {
+                ErrorCode::InvalidOptions
+            }
+            _ => ErrorCode::InvalidMove,
+        }
+    }
+
+    /// Returns the null-terminated message if it is static.
+    fn static_message(&self) -> Option<&'static str> {
+        Some(match self {
+            Self::DealerNotRandom => "only PLAYER_RAND can deal cards\0",
+            Self::CardAlreadyDealt => "this card has already been dealt\0",
+            Self::InvalidDeal => "every card must be dealt exactly once\0",
+            Self::OverDealt => "all cards have already been dealt\0",
+            Self::NoSeat => "player id does not belong to a seat\0",
+            Self::NotYourTurn => "player is currently not at turn\0",
+            Self::InvalidBiddingResponse => "invalid bidding response\0",
+            Self::InconsistentBidding => "bid contradicts the state of the bidding\0",
+            Self::NotPlaying => "game is not in the playing phase\0",
+            Self::GameOver => "game is over\0",
+            Self::InvalidBid => "invalid bid\0",
+            Self::NotNextBid => "only the next valid bid value is allowed\0",
+            Self::InvalidSkatDecision => "invalid Skat decision\0",
+            Self::PickerNotRandom => "PLAYER_RAND must pick up Skat cards\0",
+            Self::NoSkatCard => "no card in the Skat to pick up\0",
+            Self::WrongSkatCard => "not the correct card to pick up\0",
+            Self::CardElsewhere => "this card is already at another place\0",
+            Self::EmptyHand => "declarer's hand is empty\0",
+            Self::CardNotInHand => "this card is not in the declarer's hand\0",
+            Self::CannotTake => "cannot take this card for this player\0",
+            Self::LevelRequiresHand => {
+                "Schneider/Schwarz/Ouvert can only be declared in a Hand game\0"
+            }
+            Self::HandAfterPicking => "cannot declare Hand after picking up Skat\0",
+            Self::HandRequired => "you must declare a Hand game\0",
+            Self::Overbidding => "declaration would lead to overbidding\0",
+            Self::NotOverbidden => "not actually overbidden\0",
+            Self::SpitzeDisabled => "announcing Spitze is not enabled\0",
+            Self::SpitzeAnnounced => "Spitze has already been announced\0",
+            Self::SpitzeInNull => "cannot play Spitze in a Null game\0",
+            Self::SpitzeCardMissing => "declarer does not hold the lowest trump for Spitze\0",
+            Self::NotDeclarer => "only the declarer can reveal their cards\0",
+            Self::WrongRevealCard => "not the correct card for this index\0",
+            Self::UnrevealedHand => "declarer's hand is not fully revealed\0",
+            Self::HandSizeMismatch => "declarer would not hold a full hand after putting\0",
+            Self::HandHidden => "hand contains hidden cards\0",
+            Self::CardNotAllowed => "not allowed to play this card\0",
+            Self::CardValueTooHigh => "card value in move too high\0",
+            Self::SuitTooHigh => "suit code too high\0",
+            Self::InvalidDeclarationMove => "invalid declaration move\0",
+            Self::RamschDeclared => "Ramsch cannot be declared\0",
+            Self::InvalidAnnouncement => "cannot announce this now\0",
+            Self::InvalidNormalMode => "invalid normal game mode\0",
+            Self::InvalidGameLevel => "invalid game level\0",
+            #[cfg(feature = "history")]
+            Self::NothingToUndo => "no move to undo\0",
+            #[cfg(feature = "history")]
+            Self::NoInitialState => "initial state for undo was not captured\0",
+            Self::TranscriptMove(_, _) => return None,
+            Self::InvalidBidNumber(_)
+            | Self::RevealMissing(_)
+            | Self::Parse(_, _)
+            | Self::UnknownOption(_)
+            | Self::InvalidBoolOption(_)
+            | Self::ParseOptions(_) => return None,
+        })
+    }
+}
+
+impl Display for SkatError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        if let Some(message) = self.static_message() {
+            return write!(f, "{}", message.trim_end_matches('\0'));
+        }
+        match self {
+            Self::InvalidBidNumber(e) => write!(f, "failed to parse move as a valid number: {e}"),
+            Self::RevealMissing(i) => write!(f, "cannot reveal card {i} as it does not exist"),
+            Self::TranscriptMove(i, e) => write!(f, "move {i} of the transcript is invalid: {e}"),
+            Self::Parse(what, e) => write!(f, "failed to parse {what}:\n{e}"),
+            Self::UnknownOption(key) => write!(f, "unknown option: {key}"),
+            Self::InvalidBoolOption(key) => write!(f, "option {key} expects true or false"),
+            Self::ParseOptions(e) => write!(f, "failed to parse options:\n{e}"),
+            _ => unreachable!("static messages are handled above"),
+        }
+    }
+}
+
+/// Structured parsing error for editor integrations.
+#[cfg_attr(not(test), allow(dead_code))]
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub(crate) struct ParseError {
+    /// Byte offset into the input at which parsing failed.
+    pub offset: usize,
+    /// Contexts of the failing parsers from the innermost to the outermost.
+    pub context: Vec<&'static str>,
+}
+
+impl ParseError {
+    /// Converts the nom `error` which occurred while parsing `input`.
+    pub(crate) fn from_verbose(input: &str, error: VerboseError<&str>) -> Self {
+        let offset = error
+            .errors
+            .first()
+            .map_or(0, |(rest, _)| input.len() - rest.len());
+        let context = error
+            .errors
+            .iter()
+            .filter_map(|(_, kind)| match kind {
+                VerboseErrorKind::Context(c) => Some(*c),
+                _ => None,
+            })
+            .collect();
+        Self { offset, context }
+    }
+}
+
+impl Display for ParseError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "parsing failed at byte {}", self.offset)?;
+        if !self.context.is_empty() {
+            write!(f, " in {}", self.context.join(" in "))?;
+        }
+        Ok(())
+    }
+}
+
+impl From<SkatError> for Error {
+    fn from(value: SkatError) -> Self {
+        let code = value.code();
+        match value.static_message() {
+            Some(message) => Error::new_static(code, message),
+            None => Error::new_dynamic(code, value.to_string()),
+        }
+    }
+}
@@ -0,0 +1,750 @@
+use std::fmt::{self, Display};
+
+use mirabel::{
+    error::Result,
+    game::{player_id, PLAYER_NONE, PLAYER_RAND},
+};
+use nom::{
+    branch::alt,
+    bytes::complete::tag,
+    character::complete::{char, space1, u16 as parse_u16, u8 as parse_u8},
+    combinator::{cut, eof, map, map_res, opt, value},
+    error::{context, convert_error},
+    multi::{separated_list0, separated_list1},
+    sequence::{preceded, separated_pair, terminated, tuple},
+    Finish,
+};
+
+use crate::{
+    error::SkatError,
+    structures::{Card, CardSet, CardStruct, Counts, Declaration, IResult, OptCard, Player},
+    BiddingState, GameState, PlayingState, RamschResult, RamschState, Skat,
+};
+
+impl Skat {
+    /// Writes the state from the view of `player` to `buf`.
+    ///
+    /// The format consists of these `;`-separated fields: the hands from
+    /// forehand to rearhand, the Skat, the current trick, the played cards
+    /// from forehand to rearhand, the bid, the declarer, the declaration, the
+    /// state, whether _Spitze_ was announced, the number of pushes, and the
+    /// last player who picked up the Skat.
+    /// The state of a finished _Ramsch_ game includes the final tallies.
+    /// Cards are separated by spaces and written as `?` if `player` does not
+    /// know them.
+    /// [`PLAYER_NONE`] and [`PLAYER_RAND`] know all cards.
+    /// The score breakdown of a finished game is not part of the format.
+    pub(crate) fn write_state(&self, player: player_id, buf: &mut impl fmt::Write) -> Result<()> {
+        self.fmt_state(player, buf).expect("writing state failed");
+        Ok(())
+    }
+
+    /// Replaces the state of `self` with the one written by
+    /// [`Self::write_state()`] keeping the options and the seat of the
+    /// forehand.
+    pub(crate) fn read_state(&mut self, string: &str) -> Result<()> {
+        let string = string.trim();
+        let game = terminated(parse_export, eof)(string)
+            .finish()
+            .map_err(|e| SkatError::Parse("state", convert_error(string, e)))?
+            .1;
+        let game = Self {
+            forehand_seat: self.forehand_seat,
+            options: self.options,
+            ..game
+        };
+        game.check_imported()?;
+
+        *self = game;
+        Ok(())
+    }
+
+    /// Checks that an imported state could have been reached by playing.
+    ///
+    /// This covers the cards and the bidding but not, e.g., the tallies.
+    pub(crate) fn check_imported(&self) -> Result<()> {
+        let mut seen = CardSet::default();
+        for card in self.cards.iter() {
+            if seen.contains(card) {
+                return Err(SkatError::InvalidDeal.into());
+            }
+            seen.insert(card);
+        }
+        self.check_card_counts()?;
+        if let GameState::Bidding { state } = self.state {
+            self.check_bidding(state)?;
+        }
+        Ok(())
+    }
+
+    /// Returns the state from the view of `player` like
+    /// [`mirabel::game::GameMethods::export_state()`].
+    #[cfg_attr(not(test), allow(dead_code))]
+    pub fn to_export_string(&self, player: player_id) -> String {
+        let mut string = String::new();
+        self.fmt_state(player, &mut string)
+            .expect("writing state failed");
+        string
+    }
+
+    /// Creates a game with default options from the exported state `string`.
+    #[cfg_attr(not(test), allow(dead_code))]
+    pub fn from_export_string(string: &str) -> Result<Self> {
+        let mut game = Self::default();
+        game.read_state(string)?;
+        Ok(game)
+    }
+
+    /// Checks that the number of cards per location fits the state.
+    ///
+    /// This is meant for validating imported states.
+    fn check_card_counts(&self) -> Result<()> {
+        let cards = &self.cards;
+        let total = cards.hands.iter().map(|h| h.len()).sum::<usize>()
+            + cards.skat.len()
+            + cards.trick.len()
+            + cards.played.iter().map(Vec::len).sum::<usize>();
+        // Counting would panic on more cards than fit into a `u8`.
+        if total > Card::COUNT || self.expected_counts() != Some(cards.counts()) {
+            return Err(SkatError::InvalidDeal.into());
+        }
+        Ok(())
+    }
+
+    /// Returns the number of cards per location required by the state.
+    ///
+    /// The number of dealt cards, of completed tricks, of cards in the current
+    /// trick, and of cards in the Skat while picking it up or putting it back
+    /// are taken from [`Self::cards`].
+    /// Returns [`None`] if these do not fit the state.
+    fn expected_counts(&self) -> Option<Counts> {
+        const HAND: u8 = CardStruct::HAND_SIZE as u8;
+        const SKAT: u8 = CardStruct::SKAT_SIZE as u8;
+
+        let actual = self.cards.counts();
+        let mut expected = Counts {
+            hands: [HAND; Player::COUNT],
+            skat: SKAT,
+            ..Default::default()
+        };
+        match &self.state {
+            GameState::Dealing => {
+                let dealt = actual.total();
+                // The bidding starts after dealing the last card.
+                if usize::from(dealt) >= Card::COUNT {
+                    return None;
+                }
+                expected = Counts::default();
+                for target in (0..dealt).map(crate::deal_target) {
+                    match target {
+                        Some(player) => expected.hands[player as usize] += 1,
+                        None => expected.skat += 1,
+                    }
+                }
+            }
+            GameState::Bidding { .. }
+            | GameState::SkatDecision
+            | GameState::Declaring
+            | GameState::Revealing(_) => {}
+            GameState::Picking | GameState::Putting => {
+                // The state changes once the Skat is empty or full again.
+                let skat = if self.state == GameState::Picking {
+                    1..=SKAT
+                } else {
+                    0..=SKAT - 1
+                };
+                if !skat.contains(&actual.skat) {
+                    return None;
+                }
+                expected.skat = actual.skat;
+                expected.hands[self.declarer as usize] = HAND + SKAT - actual.skat;
+            }
+            GameState::Playing(_) | GameState::Finished(_) => {
+                let tricks = actual.played[0];
+                expected.played = [tricks; Player::COUNT];
+                // The players before the one to move played into the current
+                // trick.
+                let mut in_trick = [0; Player::COUNT];
+                if let GameState::Playing(state) = &self.state {
+                    if usize::from(actual.trick) >= CardStruct::TRICK_SIZE {
+                        return None;
+                    }
+                    expected.trick = actual.trick;
+                    let mut player = state.player;
+                    for _ in 0..actual.trick {
+                        player = player.next().next();
+                        in_trick[player as usize] = 1;
+                    }
+                }
+                for (hand, played) in expected.hands.iter_mut().zip(in_trick) {
+                    *hand = hand.checked_sub(tricks + played)?;
+                }
+            }
+        }
+        Some(expected)
+    }
+
+    /// Returns the cards from the view of `player` with unknown cards hidden.
+    ///
+    /// [`PLAYER_NONE`] and [`PLAYER_RAND`] know all cards as does everybody
+    /// with [`crate::options::GameOptions::perfect_information`].
+    pub(crate) fn visible_cards(&self, player: player_id) -> CardStruct {
+        let omniscient =
+            player == PLAYER_NONE || player == PLAYER_RAND || self.options.perfect_information;
+        let mut cards = self.cards.clone();
+        for p in Player::all() {
+            let visible = if omniscient || player == player_id::from(p) {
+                usize::MAX
+            } else {
+                self.revealed_count(p)
+            };
+            for card in cards[p].iter_mut().skip(visible) {
+                *card = OptCard::Hidden;
+            }
+        }
+        if !omniscient && !self.skat_known_to(player) {
+            for card in cards.skat.iter_mut() {
+                *card = OptCard::Hidden;
+            }
+        }
+        cards
+    }
+
+    /// Returns all cards `player` has seen, including their own hand.
+    ///
+    /// This is the complement of [`CardStruct::iter_unknown()`] on
+    /// [`Self::visible_cards()`].
+    #[cfg_attr(not(test), allow(dead_code))]
+    pub(crate) fn seen_cards(&self, player: player_id) -> CardSet {
+        self.visible_cards(player).iter().collect()
+    }
+
+    fn fmt_state(&self, player: player_id, f: &mut impl fmt::Write) -> fmt::Result {
+        let cards = self.visible_cards(player);
+        for p in Player::all() {
+            write_cards(f, cards[p].iter().cloned())?;
+            write!(f, ";")?;
+        }
+
+        write_cards(f, cards.skat.iter().cloned())?;
+        write!(f, ";")?;
+        write_cards(f, cards.trick.iter().map(|&c| c.into()))?;
+        for played in &cards.played {
+            write!(f, ";")?;
+            write_cards(f, played.iter().map(|&c| c.into()))?;
+        }
+
+        write!(f, ";{};{};{};", self.bid, self.declarer, self.declaration)?;
+        write_game_state(f, &self.state)?;
+        if let Some(result) = &self.ramsch_result {
+            write_ramsch(f, &result.tallies)?;
+        }
+        write!(f, ";{};{};", self.spitze, self.pushes)?;
+        write_opt(f, self.picker)
+    }
+
+    /// Returns how many cards of `player` the others can see.
+    fn revealed_count(&self, player: Player) -> usize {
+        if player != self.declarer || !self.declaration.is_ouvert() {
+            return 0;
+        }
+        match self.state {
+            GameState::Revealing(i) => i,
+            GameState::Playing(_) | GameState::Finished(_) => usize::MAX,
+            _ => 0,
+        }
+    }
+
+    /// Returns `true` if `player` knows the cards in the Skat.
+    ///
+    /// This is the last player who picked it up, i.e., the declarer or a
+    /// player who did not push it unseen before _Ramsch_.
+    fn skat_known_to(&self, player: player_id) -> bool {
+        match self.state {
+            GameState::Finished(_) if self.options.reveal_skat_after_game => true,
+            GameState::Dealing | GameState::Bidding { .. } | GameState::Picking => false,
+            _ => self.picker.map(player_id::from) == Some(player),
+        }
+    }
+}
+
+fn write_cards(f: &mut impl fmt::Write, cards: impl Iterator<Item = OptCard>) -> fmt::Result {
+    for (i, card) in cards.enumerate() {
+        if i > 0 {
+            write!(f, " ")?;
+        }
+        write!(f, "{card}")?;
+    }
+    Ok(())
+}
+
+/// Writes `value` or `-` for [`None`].
+fn write_opt(f: &mut impl fmt::Write, value: Option<impl Display>) -> fmt::Result {
+    match value {
+        Some(v) => write!(f, "{v}"),
+        None => write!(f, "-"),
+    }
+}
+
+fn write_game_state(f: &mut impl fmt::Write, state: &GameState) -> fmt::Result {
+    match state {
+        GameState::Dealing => write!(f, "dealing"),
+        GameState::Bidding { state } => write!(f, "bidding {}", u8::from(*state)),
+        GameState::SkatDecision => write!(f, "decision"),
+        GameState::Picking => write!(f, "picking"),
+        GameState::Putting => write!(f, "putting"),
+        GameState::Declaring => write!(f, "declaring"),
+        GameState::Revealing(i) => write!(f, "revealing {i}"),
+        GameState::Playing(state) => {
+            write!(f, "playing {} ", state.player)?;
+            write_opt(f, state.declarer_points)?;
+            write!(f, " ")?;
+            write_opt(f, state.team_points)?;
+            write!(f, " ")?;
+            write_opt(f, state.kontra)?;
+            write!(f, " {}", state.re)?;
+            if let Some(ramsch) = &state.ramsch {
+                write_ramsch(f, ramsch)?;
+            }
+            Ok(())
+        }
+        GameState::Finished(winners) => {
+            write!(f, "finished ")?;
+            if winners.is_empty() {
+                return write!(f, "-");
+            }
+            for (i, winner) in winners.iter().enumerate() {
+                if i > 0 {
+                    write!(f, ",")?;
+                }
+                write!(f, "{winner}")?;
+            }
+            Ok(())
+        }
+    }
+}
+
+/// Writes the points and the number of tricks per player of a _Ramsch_ game.
+fn write_ramsch(f: &mut impl fmt::Write, ramsch: &RamschState) -> fmt::Result {
+    let [a, b, c] = ramsch.trick_points;
+    let [x, y, z] = ramsch.tricks_won;
+    write!(f, " ramsch {a}/{b}/{c} {x}/{y}/{z}")
+}
+
+/// Parses the whole format written by [`Skat::write_state()`].
+///
+/// The options and the seat of the forehand of the result are the default
+/// ones.
+fn parse_export(input: &str) -> IResult<&str, Skat> {
+    map(
+        tuple((
+            context(
+                "hands",
+                tuple((
+                    parse_cards,
+                    preceded(char(';'), parse_cards),
+                    preceded(char(';'), parse_cards),
+                )),
+            ),
+            context("Skat", preceded(char(';'), parse_cards)),
+            context("trick", preceded(char(';'), parse_known_cards)),
+            context(
+                "played cards",
+                tuple((
+                    preceded(char(';'), parse_known_cards),
+                    preceded(char(';'), parse_known_cards),
+                    preceded(char(';'), parse_known_cards),
+                )),
+            ),
+            preceded(char(';'), parse_game_state),
+            context("Spitze", preceded(char(';'), parse_bool)),
+            context("pushes", preceded(char(';'), parse_u8)),
+            context("picker", preceded(char(';'), parse_opt(Player::parse))),
+        )),
+        |((fore, middle, rear), skat, trick, played, state, spitze, pushes, picker)| {
+            let ((state, ramsch), declarer, declaration, bid) = state;
+            Skat {
+                cards: CardStruct {
+                    hands: [fore.into(), middle.into(), rear.into()],
+                    skat: skat.into(),
+                    trick,
+                    played: played.into(),
+                },
+                bid,
+                declarer,
+                declaration,
+                spitze,
+                pushes,
+                picker,
+                forehand_seat: 0,
+                breakdown: None,
+                ramsch_result: ramsch.map(|tallies| RamschResult::new(tallies, pushes)),
+                #[cfg(feature = "history")]
+                history: Default::default(),
+                state,
+                options: Default::default(),
+            }
+        },
+    )(input)
+}
+
+/// A state together with the final tallies if it is a finished _Ramsch_ game.
+type StateWithTallies = (GameState, Option<RamschState>);
+
+/// Parses the bid, declarer, declaration, and state fields of the format
+/// written by [`Skat::write_state()`].
+fn parse_game_state(input: &str) -> IResult<&str, (StateWithTallies, Player, Declaration, u16)> {
+    map(
+        tuple((
+            context("bid", parse_u16),
+            preceded(char(';'), Player::parse),
+            preceded(char(';'), Declaration::parse),
+            preceded(
+                char(';'),
+                alt((parse_finished, map(parse_state, |state| (state, None)))),
+            ),
+        )),
+        |(bid, declarer, declaration, state)| (state, declarer, declaration, bid),
+    )(input)
+}
+
+fn parse_state(input: &str) -> IResult<&str, GameState> {
+    context(
+        "state",
+        alt((
+            value(GameState::Dealing, tag("dealing")),
+            map(
+                preceded(
+                    tag("bidding "),
+                    cut(map_res(parse_u8, BiddingState::try_from)),
+                ),
+                |state| GameState::Bidding { state },
+            ),
+            value(GameState::SkatDecision, tag("decision")),
+            value(GameState::Picking, tag("picking")),
+            value(GameState::Putting, tag("putting")),
+            value(GameState::Declaring, tag("declaring")),
+            map(preceded(tag("revealing "), cut(parse_u8)), |i| {
+                GameState::Revealing(i.into())
+            }),
+            map(
+                preceded(tag("playing "), cut(parse_playing)),
+                GameState::Playing,
+            ),
+        )),
+    )(input)
+}
+
+/// Parses the winners of a finished game and the tallies if it was _Ramsch_.
+fn parse_finished(input: &str) -> IResult<&str, StateWithTallies> {
+    context(
+        "finished state",
+        preceded(
+            tag("finished "),
+            cut(tuple((
+                map(
+                    alt((
+                        value(vec![], char('-')),
+                        separated_list1(char(','), Player::parse),
+                    )),
+                    GameState::Finished,
+                ),
+                opt(parse_ramsch),
+            ))),
+        ),
+    )(input)
+}
+
+fn parse_playing(input: &str) -> IResult<&str, PlayingState> {
+    context(
+        "playing state",
+        map(
+            tuple((
+                Player::parse,
+                preceded(space1, parse_opt(parse_u8)),
+                preceded(space1, parse_opt(parse_u8)),
+                preceded(space1, parse_opt(Player::parse)),
+                preceded(space1, parse_bool),
+                opt(parse_ramsch),
+            )),
+            |(player, declarer_points, team_points, kontra, re, ramsch)| PlayingState {
+                player,
+                declarer_points,
+                team_points,
+                ramsch,
+                kontra,
+                re,
+            },
+        ),
+    )(input)
+}
+
+/// Parses the tallies written by [`write_ramsch()`].
+fn parse_ramsch(input: &str) -> IResult<&str, RamschState> {
+    preceded(
+        tag(" ramsch "),
+        cut(map(
+            separated_pair(parse_tally, space1, parse_tally),
+            |(trick_points, tricks_won)| RamschState {
+                trick_points,
+                tricks_won,
+            },
+        )),
+    )(input)
+}
+
+/// Parses one number per player separated by `/`.
+fn parse_tally(input: &str) -> IResult<&str, [u8; Player::COUNT]> {
+    map(
+        tuple((
+            parse_u8,
+            preceded(char('/'), parse_u8),
+            preceded(char('/'), parse_u8),
+        )),
+        |(a, b, c)| [a, b, c],
+    )(input)
+}
+
+/// Parses the output of `parser` or `-` for [`None`].
+fn parse_opt<'a, O: Clone>(
+    parser: impl FnMut(&'a str) -> IResult<&'a str, O>,
+) -> impl FnMut(&'a str) -> IResult<&'a str, Option<O>> {
+    alt((value(None, char('-')), map(parser, Some)))
+}
+
+fn parse_bool(input: &str) -> IResult<&str, bool> {
+    alt((value(true, tag("true")), value(false, tag("false"))))(input)
+}
+
+fn parse_cards(input: &str) -> IResult<&str, Vec<OptCard>> {
+    separated_list0(char(' '), OptCard::parse)(input)
+}
+
+fn parse_known_cards(input: &str) -> IResult<&str, Vec<Card>> {
+    separated_list0(char(' '), Card::parse)(input)
+}
+
+#[cfg(test)]
+mod tests {
+    use mirabel::{game::GameMethods, game_init::GameInit};
+
+    use super::*;
+
+    /// Deals the cards of `tests/null.expect` and lets forehand declare a
+    /// _Null Hand_ game after the first trick.
+    fn null_hand_game() -> Skat {
+        let mut game = Skat::default();
+        for card in "7C 8C 9C 10C JC QC 10H JH QH KD AD 7S 8S 9S 7H KC AC 10S JS KH AH 8D 9D 8H 9H \
+                     7D QS KS AS 10D JD QD"
+            .split_whitespace()
+        {
+            game.play_str(PLAYER_RAND, card).unwrap();
+        }
+        for (player, mov) in [
+            (2, "pass"),
+            (3, "pass"),
+            (1, "accept"),
+            (1, "Hand"),
+            (1, "Null Hand"),
+            (1, "7C"),
+            (2, "10C"),
+            (3, "10H"),
+        ] {
+            game.play_str(player, mov).unwrap();
+        }
+        game
+    }
+
+    #[test]
+    fn state_round_trip() {
+        let game = null_hand_game();
+        for player in [PLAYER_NONE, 1, 2, 3] {
+            let exported = game.to_export_string(player);
+            let imported = Skat::from_export_string(&exported).unwrap();
+            assert_eq!(imported.to_export_string(player), exported);
+        }
+        let imported = Skat::from_export_string(&game.to_export_string(PLAYER_NONE)).unwrap();
+        assert_eq!(imported.cards, game.cards);
+        assert_eq!(imported.state, game.state);
+        assert_eq!(imported.declaration, game.declaration);
+    }
+
+    /// Plays a game starting with the dealt cards of `game` and checks that
+    /// every state survives an export round trip.
+    ///
+    /// The bidding, the Skat decisions, and the declaration follow `script`
+    /// as long as it lasts.
+    /// All other moves are the first legal ones, preferring _Kontra_ and _Re_.
+    fn round_trip_every_state(mut game: Skat, script: &[&str]) {
+        let mut script = script.iter();
+        loop {
+            for player in [PLAYER_NONE, 1, 2, 3] {
+                let exported = game.to_export_string(player);
+                let imported = Skat::from_export_string(&exported).unwrap();
+                assert_eq!(imported.to_export_string(player), exported);
+            }
+
+            let Some(player) = game.state.current_player(game.declarer) else {
+                break;
+            };
+            let scripted = matches!(
+                game.state,
+                GameState::Bidding { .. } | GameState::SkatDecision | GameState::Declaring
+            );
+            if let Some(mov) = scripted.then(|| script.next()).flatten() {
+                game.play_str(player, mov).unwrap();
+                continue;
+            }
+            let mut moves = vec![];
+            game.concrete_moves(&mut moves).unwrap();
+            let md = moves
+                .iter()
+                .copied()
+                .find(|&m| m == Skat::KONTRA || m == Skat::RE)
+                .unwrap_or(moves[0]);
+            game.play(player, md).unwrap();
+        }
+        assert!(matches!(game.state, GameState::Finished(_)));
+    }
+
+    #[test]
+    fn full_game_round_trip() {
+        let mut game = Skat::default();
+        game.options.kontra_re = true;
+        round_trip_every_state(game, &["18", "pass", "pass", "pick", "Grand"]);
+    }
+
+    #[test]
+    fn ouvert_game_round_trip() {
+        let script = ["18", "pass", "pass", "hand", "Grand Ouvert"];
+        round_trip_every_state(Skat::default(), &script);
+    }
+
+    #[test]
+    fn ramsch_round_trip() {
+        let mut game = Skat::default();
+        game.options.ramsch_on_all_pass = true;
+        game.options.ramsch_schieben = true;
+        round_trip_every_state(game, &["pass", "pass", "pass", "pick"]);
+    }
+
+    #[test]
+    fn all_pass_round_trip() {
+        round_trip_every_state(Skat::default(), &["pass", "pass", "pass"]);
+    }
+
+    #[test]
+    fn seen_cards() {
+        let game = null_hand_game();
+        let played: CardSet = game.cards.seen_cards().collect();
+        assert_eq!(played.len(), 3);
+        let seen = game.seen_cards(2);
+        // The own hand and the first trick but not the Skat of the Hand game.
+        assert_eq!(seen.len(), CardStruct::HAND_SIZE - 1 + 3);
+        assert!(played.iter().all(|c| seen.contains(c)));
+        assert!(game.cards.skat.iter_known().all(|c| !seen.contains(c)));
+    }
+
+    #[test]
+    fn skat_known_to_schieben_picker() {
+        let mut game = Skat::default();
+        game.options.ramsch_on_all_pass = true;
+        game.options.ramsch_schieben = true;
+        for card in "7C 8C 9C 10C JC QC 10H JH QH KD AD 7S 8S 9S 7H KC AC 10S JS KH AH 8D 9D 8H 9H \
+                     7D QS KS AS 10D JD QD"
+            .split_whitespace()
+        {
+            game.play_str(PLAYER_RAND, card).unwrap();
+        }
+        for (player, mov) in [(2, "pass"), (3, "pass"), (1, "pass"), (1, "pick")] {
+            game.play_str(player, mov).unwrap();
+        }
+        while let Some(&OptCard::Known(card)) = game.cards.skat.last() {
+            game.play_str(PLAYER_RAND, &card.to_string()).unwrap();
+        }
+        for (player, mov) in [(1, "7C"), (1, "8C"), (2, "push"), (3, "push")] {
+            game.play_str(player, mov).unwrap();
+        }
+        assert!(matches!(game.state, GameState::Playing(_)));
+        assert!(game.skat_known_to(1));
+        assert!(!game.skat_known_to(2));
+        assert!(!game.skat_known_to(3));
+        assert_eq!(game.visible_cards(1).skat, game.cards.skat);
+        assert_eq!(game.visible_cards(2).skat.hidden_count(), 2);
+
+        let exported = game.to_export_string(PLAYER_NONE);
+        let imported = Skat::from_export_string(&exported).unwrap();
+        assert_eq!(imported.picker, Some(Player::Forehand));
+    }
+
+    #[test]
+    fn skat_revealed_after_game() {
+        for reveal in [false, true] {
+            let mut game = null_hand_game();
+            game.options.reveal_skat_after_game = reveal;
+            // Middlehand wins every trick.
+            for trick in [
+                ["10S", "KH", "7S"],
+                ["JC", "AH", "8C"],
+                ["QC", "JH", "9C"],
+                ["KC", "QH", "7H"],
+                ["AC", "8D", "8H"],
+                ["JS", "9D", "8S"],
+                ["QS", "10D", "9S"],
+                ["KS", "JD", "9H"],
+                ["AS", "QD", "7D"],
+            ] {
+                for (player, card) in [2, 3, 1].into_iter().zip(trick) {
+                    game.play_str(player, card).unwrap();
+                }
+            }
+            assert!(matches!(game.state, GameState::Finished(_)));
+            assert_eq!(game.skat_known_to(2), reveal);
+            let skat = |player| {
+                let exported = game.to_export_string(player);
+                exported.split(';').nth(Player::COUNT).unwrap().to_string()
+            };
+            assert_eq!(skat(2) == skat(PLAYER_NONE), reveal);
+            assert_eq!(skat(2) == "? ?", !reveal);
+        }
+    }
+
+    #[test]
+    fn over_dealt_state() {
+        let mut game = Skat::default();
+        for card in Card::all() {
+            game.play(PLAYER_RAND, card.into()).unwrap();
+        }
+        let exported = game.to_export_string(PLAYER_NONE);
+        let (cards, rest) = exported.split_once(";bidding ").unwrap();
+        let (_, rest) = rest.split_once(';').unwrap();
+        assert!(Skat::from_export_string(&format!("{cards};dealing;{rest}")).is_err());
+
+        let mut game = Skat::from_export_string(&exported).unwrap();
+        game.state = GameState::Dealing;
+        assert_eq!(
+            game.apply_move(PLAYER_RAND, OptCard::Hidden.into()),
+            Err(SkatError::OverDealt)
+        );
+    }
+
+    #[test]
+    fn create_from_state() {
+        let exported = null_hand_game().to_export_string(PLAYER_NONE);
+        let game = Skat::create(&GameInit::Standard {
+            opts: Some("kontra_re=true"),
+            legacy: None,
+            state: Some(&exported),
+        })
+        .unwrap();
+        assert!(game.options.kontra_re);
+        assert_eq!(game.to_export_string(PLAYER_NONE), exported);
+
+        let result = Skat::create(&GameInit::Standard {
+            opts: None,
+            legacy: None,
+            state: Some("not a state"),
+        });
+        assert!(result.is_err());
+    }
+}
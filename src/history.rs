@@ -0,0 +1,168 @@
+use std::fmt::Write;
+
+use mirabel::{
+    error::Result,
+    game::{move_code, player_id},
+};
+
+use crate::{error::SkatError, replay_name, Skat};
+
+/// All moves made since the initial state of a game.
+///
+/// This allows embedders like analysis tools to step back even though
+/// mirabel itself has no undo.
+#[derive(Clone, Debug, Default)]
+pub(crate) struct History {
+    /// This is [`None`] until the initial state is captured.
+    initial: Option<Box<Skat>>,
+    moves: Vec<(player_id, move_code)>,
+}
+
+impl History {
+    /// Records the move `md` of `player` if the initial state was captured.
+    pub(crate) fn record(&mut self, player: player_id, md: move_code) {
+        if self.initial.is_some() {
+            self.moves.push((player, md));
+        }
+    }
+}
+
+impl Skat {
+    /// Starts recording the moves from the current state on.
+    pub(crate) fn capture_history(&mut self) {
+        self.history = History::default();
+        self.history.initial = Some(Box::new(self.clone()));
+    }
+
+    /// Takes back the last recorded move.
+    ///
+    /// The state is rebuilt by replaying all other moves from the initial
+    /// state.
+    // Only embedding analysis tools call this as mirabel has no undo.
+    #[cfg_attr(not(test), allow(dead_code))]
+    pub(crate) fn undo(&mut self) -> Result<()> {
+        let Some(initial) = &self.history.initial else {
+            return Err(SkatError::NoInitialState.into());
+        };
+        let Some((_, moves)) = self.history.moves.split_last() else {
+            return Err(SkatError::NothingToUndo.into());
+        };
+        let mut game = Skat::clone(initial);
+        game.history.initial = Some(initial.clone());
+        for &(player, md) in moves {
+            game.play(player, md)?;
+        }
+        *self = game;
+        Ok(())
+    }
+
+    /// Writes the recorded moves in a compact notation with one line per
+    /// phase.
+    ///
+    /// Each line holds the label of the phase followed by the comma-separated
+    /// moves, e.g., `bidding: 18, accept, pass, pass`.
+    /// The last line states the result.
+    /// Only the result is written if no initial state was captured.
+    #[cfg_attr(not(test), allow(dead_code))]
+    pub fn to_notation(&self) -> String {
+        let mut notation = String::new();
+        let mut phase = None;
+        self.replay_history(|game, _, md| {
+            let label = game.state.phase();
+            if phase == Some(label) {
+                notation.push_str(", ");
+            } else {
+                if phase.is_some() {
+                    notation.push('\n');
+                }
+                write!(notation, "{label}: ").expect("writing notation failed");
+                phase = Some(label);
+            }
+            game.write_move(md, &mut notation)
+                .expect("recorded move is invalid");
+        });
+        if phase.is_some() {
+            notation.push('\n');
+        }
+        write!(notation, "result: {}", self.state).expect("writing notation failed");
+        notation
+    }
+
+    /// Writes the recorded moves as one `<player> <move>` line per move, e.g.,
+    /// `mid 18` or `fore JC`.
+    ///
+    /// The players are named `fore`, `mid`, `rear`, and `rand` for
+    /// [`mirabel::game::PLAYER_RAND`].
+    /// This is empty if no initial state was captured.
+    #[cfg_attr(not(test), allow(dead_code))]
+    pub fn export_to_replay_format(&self) -> String {
+        let mut replay = String::new();
+        self.replay_history(|game, player, md| {
+            write!(replay, "{} ", replay_name(player)).expect("writing replay failed");
+            game.write_move(md, &mut replay)
+                .expect("recorded move is invalid");
+            replay.push('\n');
+        });
+        replay
+    }
+
+    /// Replays the recorded moves from the initial state and passes each move
+    /// together with the state before it to `f`.
+    ///
+    /// Nothing is replayed if no initial state was captured.
+    fn replay_history(&self, mut f: impl FnMut(&Skat, player_id, move_code)) {
+        let Some(initial) = &self.history.initial else {
+            return;
+        };
+        let mut game = Skat::clone(initial);
+        for &(player, md) in &self.history.moves {
+            f(&game, player, md);
+            // The moves were legal when they were recorded.
+            game.play(player, md).expect("recorded move is illegal");
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use mirabel::{
+        game::{GameMethods, PLAYER_RAND},
+        game_init::GameInit,
+    };
+
+    use super::*;
+
+    #[test]
+    fn undo_moves() {
+        let mut game = Skat::create(&GameInit::Default).unwrap();
+        assert!(game.undo().is_err());
+
+        for card in ["7C", "8C", "9C"] {
+            game.play_str(PLAYER_RAND, card).unwrap();
+        }
+        let exported = game.to_export_string(PLAYER_RAND);
+        game.play_str(PLAYER_RAND, "10C").unwrap();
+        game.undo().unwrap();
+        assert_eq!(game.to_export_string(PLAYER_RAND), exported);
+
+        for _ in 0..3 {
+            game.undo().unwrap();
+        }
+        assert_eq!(
+            game.to_export_string(PLAYER_RAND),
+            Skat::default().to_export_string(PLAYER_RAND)
+        );
+        assert!(game.undo().is_err());
+    }
+
+    #[test]
+    fn undo_requires_initial_state() {
+        let mut game = Skat::default();
+        game.play_str(PLAYER_RAND, "7C").unwrap();
+        assert!(game.undo().is_err());
+
+        game.import_state(None).unwrap();
+        game.play_str(PLAYER_RAND, "7C").unwrap();
+        game.undo().unwrap();
+    }
+}
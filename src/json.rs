@@ -0,0 +1,171 @@
+use std::{fmt, str::FromStr};
+
+use mirabel::game::player_id;
+use serde::{de, Deserialize, Deserializer, Serialize, Serializer};
+
+use crate::{
+    error::SkatError,
+    structures::{Card, CardStruct, Declaration, OptCard, Player},
+    GameState, RamschResult, Skat,
+};
+
+/// Structured view of the state as exported by [`Skat::to_json()`].
+#[derive(Serialize, Deserialize)]
+struct JsonState {
+    /// The hands, Skat, trick, and played cards.
+    #[serde(flatten)]
+    cards: CardStruct,
+    bid: u16,
+    declarer: Player,
+    declaration: Declaration,
+    spitze: bool,
+    pushes: u8,
+    picker: Option<Player>,
+    /// Tagged with the `phase` and the variant's `data`.
+    state: GameState,
+    ramsch_result: Option<RamschResult>,
+}
+
+impl Skat {
+    /// Exports the state from the view of `player` as JSON.
+    ///
+    /// This contains the same information as [`Self::write_state()`].
+    pub(crate) fn to_json(&self, player: player_id) -> String {
+        serde_json::to_string(&JsonState {
+            cards: self.visible_cards(player),
+            bid: self.bid,
+            declarer: self.declarer,
+            declaration: self.declaration,
+            spitze: self.spitze,
+            pushes: self.pushes,
+            picker: self.picker,
+            state: self.state.clone(),
+            ramsch_result: self.ramsch_result.clone(),
+        })
+        .expect("serializing state failed")
+    }
+
+    /// Creates a game with default options from the JSON written by
+    /// [`Self::to_json()`].
+    #[cfg_attr(not(test), allow(dead_code))]
+    pub(crate) fn from_json(json: &str) -> mirabel::error::Result<Self> {
+        let json: JsonState = serde_json::from_str(json)
+            .map_err(|e| SkatError::Parse("JSON state", e.to_string()))?;
+        let game = Self {
+            cards: json.cards,
+            bid: json.bid,
+            declarer: json.declarer,
+            declaration: json.declaration,
+            spitze: json.spitze,
+            pushes: json.pushes,
+            picker: json.picker,
+            state: json.state,
+            ramsch_result: json.ramsch_result,
+            ..Default::default()
+        };
+        game.check_imported()?;
+        Ok(game)
+    }
+}
+
+impl Serialize for Card {
+    /// Serializes like [`fmt::Display`], e.g., as `"10S"`.
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.collect_str(self)
+    }
+}
+
+impl<'de> Deserialize<'de> for Card {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        deserializer.deserialize_str(FromStrVisitor::new("a card like \"10S\""))
+    }
+}
+
+impl Serialize for OptCard {
+    /// Serializes like [`fmt::Display`], i.e., as `"?"` if hidden.
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.collect_str(self)
+    }
+}
+
+impl<'de> Deserialize<'de> for OptCard {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        deserializer.deserialize_str(FromStrVisitor::new("a card like \"10S\" or \"?\""))
+    }
+}
+
+/// Deserializes a string using [`FromStr`].
+struct FromStrVisitor<T> {
+    expecting: &'static str,
+    marker: std::marker::PhantomData<T>,
+}
+
+impl<T> FromStrVisitor<T> {
+    fn new(expecting: &'static str) -> Self {
+        Self {
+            expecting,
+            marker: std::marker::PhantomData,
+        }
+    }
+}
+
+impl<'de, T: FromStr> de::Visitor<'de> for FromStrVisitor<T> {
+    type Value = T;
+
+    fn expecting(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.expecting)
+    }
+
+    fn visit_str<E: de::Error>(self, v: &str) -> Result<Self::Value, E> {
+        v.parse()
+            .map_err(|_| E::invalid_value(de::Unexpected::Str(v), &self))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use mirabel::game::PLAYER_NONE;
+
+    use super::*;
+    use crate::options::GameOptions;
+
+    #[test]
+    fn json_hides_cards() {
+        let mut game = Skat::default();
+        for card in ["JC", "JS", "JH"] {
+            game.play_str(mirabel::game::PLAYER_RAND, card).unwrap();
+        }
+        let json: serde_json::Value = serde_json::from_str(&game.to_json(2)).unwrap();
+        assert_eq!(json["hands"][0], serde_json::json!(["?", "?", "?"]));
+        assert_eq!(json["state"]["phase"], "dealing");
+
+        let json: serde_json::Value = serde_json::from_str(&game.to_json(1)).unwrap();
+        assert_eq!(json["hands"][0], serde_json::json!(["JC", "JS", "JH"]));
+    }
+
+    #[test]
+    fn json_round_trip() {
+        let mut game = Skat::default();
+        game.options.ramsch_on_all_pass = true;
+        while let Some(player) = game.state.current_player(game.declarer) {
+            for view in [PLAYER_NONE, 1, 2, 3] {
+                let json = game.to_json(view);
+                assert_eq!(Skat::from_json(&json).unwrap().to_json(view), json);
+            }
+            let mut moves = vec![];
+            game.concrete_moves(&mut moves).unwrap();
+            game.play(player, moves[0]).unwrap();
+        }
+        let imported = Skat::from_json(&game.to_json(PLAYER_NONE)).unwrap();
+        assert_eq!(imported.state, game.state);
+        assert_eq!(imported.ramsch_result, game.ramsch_result);
+        assert!(Skat::from_json("{}").is_err());
+    }
+
+    #[test]
+    fn json_print_option() {
+        let options: GameOptions = "json_print=true".parse().unwrap();
+        assert!(options.json_print);
+        assert_eq!(options.to_string().parse::<GameOptions>().unwrap(), options);
+    }
+}
@@ -3,6 +3,16 @@
 //! [_surena_](https://github.com/RememberOfLife/surena) game engine and the
 //! [_mirabel_](https://github.com/RememberOfLife/mirabel) game GUI.
 
+// Network sync for embedders which is not wired into mirabel yet.
+#[cfg_attr(not(test), allow(dead_code))]
+mod delta;
+mod error;
+mod export;
+#[cfg(feature = "history")]
+mod history;
+#[cfg(feature = "serde")]
+mod json;
+mod options;
 mod structures;
 
 use core::panic;
@@ -13,7 +23,7 @@ use std::{
 
 use mirabel::{
     cstr,
-    error::{Error, ErrorCode, Result},
+    error::{Error, Result},
     game::{
         move_code, player_id, semver, GameFeatures, GameMethods, Metadata, MoveCode, MoveData,
         PLAYER_RAND,
@@ -22,13 +32,30 @@ use mirabel::{
     plugin_get_game_methods, MoveDataSync,
 };
 
-use structures::{Card, CardStruct, Declaration, DeclarationMove, Matadors, Player};
+use error::{SkatError, SkatResult};
+use options::{BiddingOrder, CardNames, GameOptions};
+use structures::{
+    Card, CardSet, CardStruct, Declaration, DeclarationMove, EnglishCard, GameLevel, GermanCard,
+    HandMetrics, Matadors, NormalMode, Player,
+};
 
 use crate::structures::OptCard;
 
-#[derive(Clone, Debug, Default)]
+/// The phases of a game.
+///
+/// Only chance events, i.e., dealing and picking up the Skat, are performed by
+/// [`PLAYER_RAND`].
+/// All other moves are made by the player holding the respective cards.
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+#[cfg_attr(
+    feature = "serde",
+    derive(serde::Serialize, serde::Deserialize),
+    serde(tag = "phase", content = "data", rename_all = "snake_case")
+)]
 enum GameState {
     /// State while dealing cards.
+    ///
+    /// This is performed by [`PLAYER_RAND`].
     #[default]
     Dealing,
     /// State of the bidding phase.
@@ -46,6 +73,7 @@ enum GameState {
     Declaring,
     /// Single player is revealing their cards.
     ///
+    /// This is no chance event as the declarer knows their own cards.
     /// Stores the index of the next card to reveal.
     Revealing(usize),
     /// The actual trick-taking game is going on.
@@ -75,6 +103,33 @@ impl GameState {
                     | GameState::Declaring
             )
     }
+
+    /// Returns the label of the phase used by [`Skat::to_notation()`].
+    #[cfg(feature = "history")]
+    fn phase(&self) -> &'static str {
+        match self {
+            GameState::Dealing => "deal",
+            GameState::Bidding { state: _ } => "bidding",
+            GameState::SkatDecision | GameState::Picking | GameState::Putting => "skat",
+            GameState::Declaring | GameState::Revealing(_) => "declaration",
+            GameState::Playing(_) => "tricks",
+            GameState::Finished(_) => "result",
+        }
+    }
+
+    /// Returns the player whose turn it is or `None` if the game is over.
+    fn current_player(&self, declarer: Player) -> Option<player_id> {
+        Some(match self {
+            GameState::Dealing | GameState::Picking => PLAYER_RAND,
+            GameState::Bidding { state } => state.source().into(),
+            GameState::SkatDecision
+            | GameState::Putting
+            | GameState::Declaring
+            | GameState::Revealing(_) => declarer.into(),
+            GameState::Playing(state) => state.player.into(),
+            GameState::Finished(_) => return None,
+        })
+    }
 }
 
 impl Display for GameState {
@@ -99,7 +154,9 @@ impl Display for GameState {
                         "{} won",
                         players
                             .iter()
-                            .fold("".to_string(), |a, b| format!("{a} and {b}"))
+                            .map(ToString::to_string)
+                            .collect::<Vec<_>>()
+                            .join(" and ")
                     )
                 }
             }
@@ -108,6 +165,7 @@ impl Display for GameState {
 }
 
 #[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 enum BiddingState {
     #[default]
     MiddleCallsFore,
@@ -119,6 +177,8 @@ enum BiddingState {
     /// Forehand is free to decide whether to play or not.
     ///
     /// This happens when middlehand and rearhand directly pass.
+    /// Accepting in this state commits the forehand to [`Skat::MINIMUM_BID`]
+    /// and is therefore written as this bid.
     Forehand,
 }
 
@@ -167,18 +227,57 @@ impl BiddingState {
         }
     }
 
+    /// Returns the state in which the bidding starts.
+    fn first(order: BiddingOrder) -> Self {
+        match order {
+            BiddingOrder::Standard => Self::MiddleCallsFore,
+            BiddingOrder::RearhandFirst => Self::RearCallsMiddle,
+        }
+    }
+
+    /// Returns how many players have not passed yet.
+    fn active_bidder_count(&self, order: BiddingOrder) -> usize {
+        match (self, order) {
+            (Self::MiddleCallsFore | Self::ForeRespondsMiddle, BiddingOrder::Standard)
+            | (Self::RearCallsMiddle | Self::MiddleRespondsRear, BiddingOrder::RearhandFirst) => {
+                Player::COUNT
+            }
+            (Self::Forehand, _) => 1,
+            _ => Player::COUNT - 1,
+        }
+    }
+
     /// Evaluate next state after [`Self::source`] `passed` or not.
-    fn next(&self, passed: bool, any_bid: bool) -> BiddingResult {
+    ///
+    /// The `order` decides who meets the survivor of the first pairing.
+    fn next(&self, passed: bool, any_bid: bool, order: BiddingOrder) -> BiddingResult {
+        use BiddingOrder::{RearhandFirst, Standard};
+
         if passed {
-            match self {
-                Self::MiddleCallsFore => BiddingResult::Continue(Self::RearCallsFore),
-                Self::ForeRespondsMiddle => BiddingResult::Continue(Self::RearCallsMiddle),
-                Self::RearCallsFore if any_bid => BiddingResult::Finished(Player::Forehand),
-                Self::RearCallsFore => BiddingResult::Continue(Self::Forehand),
-                Self::ForeRespondsRear => BiddingResult::Finished(Player::Rearhand),
-                Self::RearCallsMiddle => BiddingResult::Finished(Player::Middlehand),
-                Self::MiddleRespondsRear => BiddingResult::Finished(Player::Rearhand),
-                Self::Forehand => BiddingResult::Draw,
+            match (self, order) {
+                (Self::MiddleCallsFore, Standard) => BiddingResult::Continue(Self::RearCallsFore),
+                (Self::ForeRespondsMiddle, Standard) => {
+                    BiddingResult::Continue(Self::RearCallsMiddle)
+                }
+                (Self::RearCallsMiddle, RearhandFirst) => {
+                    BiddingResult::Continue(Self::MiddleCallsFore)
+                }
+                (Self::MiddleRespondsRear, RearhandFirst) => {
+                    BiddingResult::Continue(Self::RearCallsFore)
+                }
+                (Self::RearCallsFore | Self::MiddleCallsFore, _) if any_bid => {
+                    BiddingResult::Finished(Player::Forehand)
+                }
+                (Self::RearCallsFore | Self::MiddleCallsFore, _) => {
+                    BiddingResult::Continue(Self::Forehand)
+                }
+                (Self::ForeRespondsRear, _) => BiddingResult::Finished(Player::Rearhand),
+                (Self::ForeRespondsMiddle, RearhandFirst) => {
+                    BiddingResult::Finished(Player::Middlehand)
+                }
+                (Self::RearCallsMiddle, Standard) => BiddingResult::Finished(Player::Middlehand),
+                (Self::MiddleRespondsRear, Standard) => BiddingResult::Finished(Player::Rearhand),
+                (Self::Forehand, _) => BiddingResult::Draw,
             }
         } else {
             match self {
@@ -194,6 +293,30 @@ impl BiddingState {
     }
 }
 
+impl From<BiddingState> for u8 {
+    fn from(value: BiddingState) -> Self {
+        value as u8
+    }
+}
+
+impl TryFrom<u8> for BiddingState {
+    type Error = Error;
+
+    /// Inverse of the conversion into [`u8`] for importing states.
+    fn try_from(value: u8) -> std::result::Result<Self, Self::Error> {
+        Ok(match value {
+            0 => Self::MiddleCallsFore,
+            1 => Self::ForeRespondsMiddle,
+            2 => Self::RearCallsFore,
+            3 => Self::ForeRespondsRear,
+            4 => Self::RearCallsMiddle,
+            5 => Self::MiddleRespondsRear,
+            6 => Self::Forehand,
+            7.. => return Err(SkatError::InconsistentBidding.into()),
+        })
+    }
+}
+
 impl Display for BiddingState {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
         if matches!(self, Self::Forehand) {
@@ -214,6 +337,7 @@ impl Display for BiddingState {
     }
 }
 
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
 enum BiddingResult {
     /// Bidding continues.
     Continue(BiddingState),
@@ -223,16 +347,72 @@ enum BiddingResult {
     Draw,
 }
 
-#[derive(Debug, Clone)]
+impl BiddingResult {
+    /// Returns `true` if everybody passed.
+    fn is_draw(&self) -> bool {
+        matches!(self, Self::Draw)
+    }
+
+    /// Returns `true` if somebody became declarer.
+    #[cfg_attr(not(test), allow(dead_code))]
+    fn is_finished(&self) -> bool {
+        matches!(self, Self::Finished(_))
+    }
+
+    /// Returns the new declarer if the bidding finished.
+    fn declarer(self) -> Option<Player> {
+        match self {
+            Self::Finished(p) => Some(p),
+            _ => None,
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 struct PlayingState {
     player: Player,
     declarer_points: Option<u8>,
     team_points: Option<u8>,
+    /// Tallies per player if this is a _Ramsch_ game.
+    ramsch: Option<RamschState>,
+    /// The defender who announced _Kontra_.
+    kontra: Option<Player>,
+    /// The declarer answered _Kontra_ with _Re_.
+    re: bool,
+}
+
+impl PlayingState {
+    /// Returns the card points taken in completed tricks by both parties.
+    ///
+    /// This is always zero in _Ramsch_ games which use their own tallies.
+    fn total_points_seen(&self) -> u8 {
+        self.declarer_points.unwrap_or(0) + self.team_points.unwrap_or(0)
+    }
 }
 
 impl Display for PlayingState {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
         write!(f, "it is {}'s turn", self.player)?;
+        if let Some(ramsch) = &self.ramsch {
+            for player in Player::all() {
+                writeln!(f)?;
+                write!(
+                    f,
+                    "{player} has {} points in {} tricks",
+                    ramsch.trick_points[player as usize], ramsch.tricks_won[player as usize]
+                )?;
+            }
+            return Ok(());
+        }
+        if let Some(player) = self.kontra {
+            writeln!(f)?;
+            write!(f, "{player} announced Kontra")?;
+        }
+        if self.re {
+            writeln!(f)?;
+            write!(f, "declarer announced Re")?;
+        }
         for (name, points) in [
             ("declarer", self.declarer_points),
             ("team", self.team_points),
@@ -254,10 +434,227 @@ impl Default for PlayingState {
             player: Player::Forehand,
             declarer_points: Default::default(),
             team_points: Default::default(),
+            ramsch: Default::default(),
+            kontra: Default::default(),
+            re: Default::default(),
+        }
+    }
+}
+
+/// Derivation of the score of a normal or _Null_ game.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+struct GameValueBreakdown {
+    declaration: Declaration,
+    /// Base value of the declaration.
+    base: i16,
+    bid: i16,
+    /// Number of (missing) matadors.
+    matadors: u8,
+    hand: bool,
+    /// Reached or announced _Schneider_.
+    schneider: bool,
+    schneider_announced: bool,
+    /// Reached or announced _Schwarz_.
+    schwarz: bool,
+    schwarz_announced: bool,
+    ouvert: bool,
+    /// Announced _Spitze_.
+    spitze: bool,
+    /// The declarer met all conditions ignoring overbidding.
+    won: bool,
+    /// Number of times the result is doubled by _Kontra_ and _Re_.
+    doublings: u8,
+}
+
+impl GameValueBreakdown {
+    /// Returns the multiplier of the base value.
+    ///
+    /// This is always one for _Null_ games.
+    fn multiplier(&self) -> i16 {
+        if self.declaration.is_null() {
+            return 1;
+        }
+        1 + i16::from(self.matadors)
+            + i16::from(self.hand)
+            + i16::from(self.schneider)
+            + i16::from(self.schneider_announced)
+            + i16::from(self.schwarz)
+            + i16::from(self.schwarz_announced)
+            + i16::from(self.ouvert)
+            + i16::from(self.spitze)
+    }
+
+    fn value(&self) -> i16 {
+        self.base * self.multiplier()
+    }
+
+    /// Is the game lost because its value is below the bid.
+    fn overbid(&self) -> bool {
+        self.value() < self.bid
+    }
+
+    /// Returns the points for the declarer's score.
+    fn points(&self) -> i16 {
+        let points = if self.won && !self.overbid() {
+            self.value()
+        } else {
+            -2 * self.value().max(self.bid)
+        };
+        points << self.doublings
+    }
+}
+
+impl Display for GameValueBreakdown {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}: {}", self.declaration, self.base)?;
+        if !self.declaration.is_null() {
+            write!(f, " * (1 game + {} matadors", self.matadors)?;
+            for (set, name) in [
+                (self.hand, "Hand"),
+                (self.schneider, "Schneider"),
+                (self.schneider_announced, "Schneider announced"),
+                (self.schwarz, "Schwarz"),
+                (self.schwarz_announced, "Schwarz announced"),
+                (self.ouvert, "Ouvert"),
+                (self.spitze, "Spitze"),
+            ] {
+                if set {
+                    write!(f, " + 1 {name}")?;
+                }
+            }
+            write!(f, ")")?;
+        }
+        write!(f, " = {}", self.value())?;
+        if self.overbid() {
+            write!(f, ", overbid with {}", self.bid)?;
+        } else if !self.won {
+            write!(f, ", lost")?;
+        }
+        for _ in 0..self.doublings {
+            write!(f, ", doubled")?;
+        }
+        write!(f, "\nscore: {}", self.points())
+    }
+}
+
+/// Whether _Schneider_ is already decided while playing a normal game.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum SchneiderStatus {
+    /// Either party can still end up _Schneider_.
+    Open,
+    /// The defenders can no longer escape _Schneider_.
+    Secured,
+    /// The declarer can no longer escape _Schneider_.
+    DeclarerSchneider,
+    /// Both parties escaped _Schneider_.
+    Avoided,
+}
+
+impl Display for SchneiderStatus {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Open => write!(f, "Schneider still open"),
+            Self::Secured => write!(f, "defenders are Schneider"),
+            Self::DeclarerSchneider => write!(f, "declarer is Schneider"),
+            Self::Avoided => write!(f, "nobody is Schneider"),
+        }
+    }
+}
+
+/// Tallies of a _Ramsch_ game.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+struct RamschState {
+    trick_points: [u8; Player::COUNT],
+    tricks_won: [u8; Player::COUNT],
+}
+
+impl RamschState {
+    /// Returns the player who took all tricks (_Durchmarsch_) if any.
+    fn durchmarsch(&self) -> Option<Player> {
+        Player::all()
+            .into_iter()
+            .find(|&p| usize::from(self.tricks_won[p as usize]) == CardStruct::HAND_SIZE)
+    }
+
+    /// Returns `true` if `player` is tied for the most points.
+    fn loses(&self, player: Player) -> bool {
+        let most = self.trick_points.iter().copied().max().unwrap_or_default();
+        self.trick_points[player as usize] == most
+    }
+
+    /// Calculates the scores of all players when the game is over.
+    ///
+    /// All players tied for the most points lose these points.
+    /// A player taking all tricks (_Durchmarsch_) wins instead.
+    /// Each of the `pushes` of the Skat unseen doubles the scores.
+    fn scores(&self, pushes: u8) -> [i16; Player::COUNT] {
+        let mut scores = [0; Player::COUNT];
+        if let Some(player) = self.durchmarsch() {
+            scores[player as usize] = i16::from(Card::POINTS_TOTAL) << pushes;
+            return scores;
+        }
+
+        for player in Player::all() {
+            if self.loses(player) {
+                scores[player as usize] = -i16::from(self.trick_points[player as usize]) << pushes;
+            }
+        }
+        scores
+    }
+
+    /// Returns the player who made a _Durchmarsch_ or else all players who
+    /// did not lose.
+    ///
+    /// This is empty if all players are tied.
+    fn winners(&self) -> Vec<Player> {
+        if let Some(player) = self.durchmarsch() {
+            return vec![player];
+        }
+        Player::all()
+            .into_iter()
+            .filter(|&p| !self.loses(p))
+            .collect()
+    }
+}
+
+/// Outcome of a _Ramsch_ game once it is over.
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+struct RamschResult {
+    /// The final tallies including the Skat.
+    tallies: RamschState,
+    scores: [i16; Player::COUNT],
+}
+
+impl RamschResult {
+    /// Scores the final `tallies` after the Skat was pushed `pushes` times.
+    fn new(tallies: RamschState, pushes: u8) -> Self {
+        Self {
+            scores: tallies.scores(pushes),
+            tallies,
         }
     }
 }
 
+impl Display for RamschResult {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        for (i, player) in Player::all().into_iter().enumerate() {
+            if i > 0 {
+                writeln!(f)?;
+            }
+            write!(
+                f,
+                "{player} has {} points in {} tricks and scores {}",
+                self.tallies.trick_points[player as usize],
+                self.tallies.tricks_won[player as usize],
+                self.scores[player as usize]
+            )?;
+        }
+        Ok(())
+    }
+}
+
 #[derive(Clone, Debug)]
 struct Skat {
     cards: CardStruct,
@@ -266,21 +663,216 @@ struct Skat {
     /// The one player playing against the rest.
     declarer: Player,
     declaration: Declaration,
+    /// The declarer announced _Spitze_.
+    spitze: bool,
+    /// How often the Skat was pushed unseen before playing _Ramsch_.
+    pushes: u8,
+    /// The last player who picked up the Skat and thus knows its cards.
+    picker: Option<Player>,
+    /// Physical seat of the forehand which rotates with every deal of a
+    /// series.
+    forehand_seat: u8,
+    /// Derivation of the score once a normal or _Null_ game is over.
+    breakdown: Option<GameValueBreakdown>,
+    /// Tallies and scores once a _Ramsch_ game is over.
+    ramsch_result: Option<RamschResult>,
+    /// The moves for taking them back.
+    #[cfg(feature = "history")]
+    history: history::History,
     // mode: GameMode,
     state: GameState,
+    options: GameOptions,
 }
 
 impl Skat {
     const MINIMUM_BID: u16 = 18;
     const MAXIMUM_BID: u16 = 264;
-    /// Declarer is winner with at least this many points.
-    const POINTS_WINNING: u8 = 61;
-    /// Party is _Schneider_ when having these many or less points.
-    const POINTS_SCHNEIDER: u8 = 30;
+    /// Move code for announcing _Kontra_ while playing.
+    const KONTRA: move_code = Card::COUNT as move_code;
+    /// Move code for announcing _Re_ while playing.
+    const RE: move_code = Self::KONTRA + 1;
+
+    /// Returns the next valid bid value above `bid` if any.
+    ///
+    /// This is [`None`] once `bid` reaches [`Self::MAXIMUM_BID`].
+    // There is no static table of bids to search as the values of Null games
+    // are configurable, and scanning at most `MAXIMUM_BID` values is cheap.
+    fn next_valid_bid_after(&self, bid: u16) -> Option<u16> {
+        (bid.saturating_add(1)..=Self::MAXIMUM_BID)
+            .find(|&b| Declaration::is_game_value(b, &self.options.null_values))
+    }
+
+    /// Returns all valid bid values above `current_bid` in ascending order.
+    fn legal_bids_for_call(&self, current_bid: u16) -> impl Iterator<Item = u16> + '_ {
+        std::iter::successors(self.next_valid_bid_after(current_bid), |&b| {
+            self.next_valid_bid_after(b)
+        })
+    }
+
+    /// Checks that the current `bid` is consistent with the bidding `state`.
+    ///
+    /// This is meant for validating imported states.
+    fn check_bidding(&self, state: BiddingState) -> Result<()> {
+        let consistent = match state {
+            // Nobody made a call yet.
+            BiddingState::Forehand => self.bid == Self::MINIMUM_BID - 1,
+            // There must be a call to respond to.
+            _ if state.respond() => Declaration::is_game_value(self.bid, &self.options.null_values),
+            _ => {
+                self.bid == Self::MINIMUM_BID - 1
+                    || Declaration::is_game_value(self.bid, &self.options.null_values)
+            }
+        };
+        if !consistent {
+            return Err(SkatError::InconsistentBidding.into());
+        }
+        Ok(())
+    }
+
+    /// Creates a game which is already in [`GameState::Playing`].
+    ///
+    /// This skips dealing, bidding, and declaring, e.g., for setting up test
+    /// scenarios.
+    #[cfg_attr(not(test), allow(dead_code))]
+    fn from_hands_skat_bid_declaration(
+        hands: [[Card; CardStruct::HAND_SIZE]; Player::COUNT],
+        skat: [Card; CardStruct::SKAT_SIZE],
+        bid: u16,
+        declarer: Player,
+        declaration: Declaration,
+    ) -> Result<Self> {
+        let dealt: CardSet = hands.iter().flatten().chain(skat.iter()).copied().collect();
+        if dealt.len() != Card::COUNT {
+            return Err(SkatError::InvalidDeal.into());
+        }
+
+        let mut game = Self {
+            bid,
+            declarer,
+            declaration,
+            picker: (!declaration.is_hand()).then_some(declarer),
+            state: GameState::Playing(Default::default()),
+            ..Default::default()
+        };
+        if !Declaration::is_game_value(bid, &game.options.null_values) {
+            return Err(SkatError::InvalidBid.into());
+        }
+        for (player, hand) in Player::all().into_iter().zip(hands) {
+            game.cards[player] = (&hand[..]).into();
+        }
+        game.cards.skat = (&skat[..]).into();
+
+        let matadors = game
+            .calculate_matadors()
+            .expect("all cards should be known");
+        if !declaration.allowed(bid, &matadors, &game.options.null_values) {
+            return Err(SkatError::Overbidding.into());
+        }
+        Ok(game)
+    }
+
+    /// Returns the number of card assignments consistent with the visible
+    /// information.
+    ///
+    /// The unknown cards are distributed among the hidden cards of the hands
+    /// and the Skat.
+    #[cfg_attr(not(test), allow(dead_code))]
+    fn possible_states_count(&self) -> u128 {
+        let mut unknown = self.cards.iter_unknown().count();
+        let mut count = 1;
+        for hidden in self
+            .cards
+            .hands
+            .iter()
+            .chain([&self.cards.skat])
+            .map(|v| v.hidden_count())
+        {
+            count *= binomial(unknown, hidden);
+            unknown = unknown.saturating_sub(hidden);
+        }
+        count
+    }
+
+    /// Resets `self` to the initial state but keeps the options.
+    ///
+    /// This avoids reallocating the cards for consecutive games.
+    fn reset(&mut self) {
+        self.cards.clear();
+        let cards = std::mem::take(&mut self.cards);
+        *self = Self {
+            cards,
+            options: self.options,
+            ..Default::default()
+        };
+    }
+
+    /// Returns a fresh game for the next deal of a series.
+    ///
+    /// The options are carried forward and the forehand moves on by one
+    /// seat.
+    #[cfg_attr(not(test), allow(dead_code))]
+    pub(crate) fn next_deal(&self) -> Self {
+        let mut game = self.clone();
+        game.reset();
+        game.forehand_seat = (self.forehand_seat + 1) % Player::COUNT as u8;
+        game
+    }
+
+    /// Is a _Ramsch_ game going to be played.
+    fn is_ramsch(&self) -> bool {
+        matches!(self.declaration, Declaration::Ramsch)
+    }
+
+    /// Starts playing _Ramsch_.
+    fn start_ramsch(&mut self) {
+        self.state = GameState::Playing(PlayingState {
+            ramsch: Some(Default::default()),
+            ..Default::default()
+        });
+    }
+
+    /// Passes the Skat on to the next player while pushing it before _Ramsch_.
+    ///
+    /// The game starts after the rearhand.
+    fn push_skat(&mut self) {
+        if self.declarer == Player::Rearhand {
+            self.start_ramsch();
+        } else {
+            self.declarer = self.declarer.next();
+            self.state = GameState::SkatDecision;
+        }
+    }
+
+    /// Returns the value the declared game must at least be worth.
+    fn minimum_game_value_required(&self) -> u16 {
+        self.bid
+    }
+
+    /// Returns the declarations which reach the bid without relying on
+    /// _Schneider_ or _Schwarz_.
+    ///
+    /// This is empty if the relevant cards of the declarer are unknown.
+    #[cfg_attr(not(test), allow(dead_code))]
+    fn safe_declarations(&self) -> Vec<Declaration> {
+        let Some(matadors) = self.calculate_matadors() else {
+            return vec![];
+        };
+        Declaration::all(self.declaration.is_hand())
+            .into_iter()
+            .filter(|d| {
+                d.safe(
+                    self.minimum_game_value_required(),
+                    &matadors,
+                    &self.options.null_values,
+                )
+            })
+            .collect()
+    }
 
     /// Calculate the (missing) matadors for the declarer.
     ///
     /// The Skat is only considered if the declaration is not a _Hand_ game.
+    /// The cards the declarer already played are included.
     /// Returns [`Node`] if any used cards are [`OptCard::Hidden`].
     fn calculate_matadors(&self) -> Option<Matadors> {
         // FIXME: Avoid allocation.
@@ -288,6 +880,17 @@ impl Skat {
         if !self.declaration.is_hand() {
             cards.extend_from_slice(&self.cards.skat);
         }
+        cards.extend(
+            self.cards.played[self.declarer as usize]
+                .iter()
+                .map(|&c| OptCard::from(c)),
+        );
+        if let GameState::Playing(state) = &self.state {
+            let trick = &self.cards.trick;
+            let leader = (state.player as usize + Player::COUNT - trick.len()) % Player::COUNT;
+            let i = (self.declarer as usize + Player::COUNT - leader) % Player::COUNT;
+            cards.extend(trick.get(i).map(|&c| OptCard::from(c)));
+        }
         if cards.iter().any(|c| matches!(c, OptCard::Hidden)) {
             return None;
         }
@@ -297,6 +900,54 @@ impl Skat {
         })))
     }
 
+    /// Describes the game in one line like `clubs (2 matadors, value 36)`.
+    ///
+    /// The matadors and value are only included if known.
+    fn describe_game_type(&self) -> String {
+        if let Some(breakdown) = &self.breakdown {
+            return match breakdown.declaration {
+                Declaration::Normal(_, _) => format!(
+                    "{} ({} matadors, value {})",
+                    breakdown.declaration,
+                    breakdown.matadors,
+                    breakdown.value()
+                ),
+                declaration => format!("{declaration} (value {})", breakdown.value()),
+            };
+        }
+        let Some(declaration) = self.declaration() else {
+            return match self.state {
+                GameState::Dealing | GameState::Bidding { .. } => "bidding".to_string(),
+                GameState::Finished(_) if self.is_ramsch() => self.declaration.to_string(),
+                GameState::Finished(_) => "everybody passed".to_string(),
+                _ => "no declaration yet".to_string(),
+            };
+        };
+        match (declaration, self.calculate_matadors()) {
+            (Declaration::Normal(mode, _), Some(matadors)) => format!(
+                "{declaration} ({} matadors, value {})",
+                matadors[mode],
+                declaration.safe_value(&matadors, &self.options.null_values)
+            ),
+            _ => declaration.to_string(),
+        }
+    }
+
+    /// Returns the state of the bidding phase if in [`GameState::Bidding`].
+    fn bidding_state(&self) -> Option<&BiddingState> {
+        match &self.state {
+            GameState::Bidding { state } => Some(state),
+            _ => None,
+        }
+    }
+
+    /// Returns the highest bid so far regardless of the phase.
+    ///
+    /// This is below [`Self::MINIMUM_BID`] if nobody has bid yet.
+    fn current_bid(&self) -> u16 {
+        self.bid
+    }
+
     /// Return the declaration if [`GameState::has_declaration()`] is `true`.
     fn declaration(&self) -> Option<Declaration> {
         if self.state.has_declaration() {
@@ -306,361 +957,725 @@ impl Skat {
         }
     }
 
-    /// Calculates the points for the declarer's score when the game is over.
+    /// Describes what the declarer needs to achieve to win the game.
+    fn describe_winning_condition(&self) -> String {
+        let Some(declaration) = self.declaration() else {
+            return "game not declared yet".to_string();
+        };
+        let thresholds = self.options.point_thresholds;
+        if matches!(declaration, Declaration::Ramsch) {
+            "Take as few card points as possible".to_string()
+        } else if declaration.is_null() {
+            "Take zero tricks".to_string()
+        } else if declaration.is_schwarz() {
+            "Win all 10 tricks (Schwarz announced)".to_string()
+        } else if declaration.is_schneider() {
+            format!(
+                "Win at least {} points (Schneider announced)",
+                Card::POINTS_TOTAL - thresholds.schneider
+            )
+        } else {
+            format!("Win at least {} card points", thresholds.winning)
+        }
+    }
+
+    /// Writes every completed trick with its winner and points.
+    ///
+    /// This is meant for finished games for which [`Self::declaration()`]
+    /// returns [`None`].
+    fn fmt_trick_history(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        for (i, (trick, winner)) in self
+            .cards
+            .completed_tricks(self.declaration)
+            .iter()
+            .enumerate()
+        {
+            write!(f, "trick {}:", i + 1)?;
+            for &card in trick {
+                write!(f, " ")?;
+                self.fmt_card(card, f)?;
+            }
+            let points: u8 = trick.iter().copied().sum();
+            writeln!(f, " → {winner} wins ({points} points)")?;
+        }
+        let points = self.cards.points_by_player(self.declaration);
+        let totals: Vec<_> = Player::all()
+            .into_iter()
+            .map(|p| format!("{p} {}", points[p as usize]))
+            .collect();
+        writeln!(f, "points from tricks: {}", totals.join(", "))
+    }
+
+    /// Writes `card` with the names chosen in the options.
+    ///
+    /// The alternate form of the short names uses suit symbols.
+    fn fmt_card(&self, card: Card, f: &mut fmt::Formatter) -> fmt::Result {
+        match self.options.card_names {
+            CardNames::Short => card.fmt(f),
+            CardNames::English => EnglishCard(card).fmt(f),
+            CardNames::German => GermanCard(card).fmt(f),
+        }
+    }
+
+    /// Returns the card points of the declarer, the defenders, and those still
+    /// in play.
+    ///
+    /// The known cards of the Skat count for the declarer.
+    /// Returns [`None`] if not playing a normal game.
+    fn party_points(&self) -> Option<(u8, u8, u8)> {
+        let GameState::Playing(state) = &self.state else {
+            return None;
+        };
+        if !matches!(self.declaration, Declaration::Normal(_, _)) {
+            return None;
+        }
+
+        let skat: u8 = self.cards.skat.iter_known().sum();
+        let declarer = state.declarer_points.unwrap_or_default() + skat;
+        let defenders = state.team_points.unwrap_or_default();
+        // The current trick is still undecided.
+        let trick: u8 = self.cards.trick.iter().copied().sum();
+        let open = self.cards.points_remaining(self.declaration) + trick;
+        Some((declarer, defenders, open))
+    }
+
+    /// Returns whether _Schneider_ is already decided based on the points
+    /// taken and the points still in play.
+    ///
+    /// Returns [`None`] if not playing a normal game.
+    fn schneider_status(&self) -> Option<SchneiderStatus> {
+        let (declarer, defenders, open) = self.party_points()?;
+        let schneider = self.options.point_thresholds.schneider;
+        Some(if declarer > schneider && defenders > schneider {
+            SchneiderStatus::Avoided
+        } else if defenders + open <= schneider {
+            SchneiderStatus::Secured
+        } else if declarer + open <= schneider {
+            SchneiderStatus::DeclarerSchneider
+        } else {
+            SchneiderStatus::Open
+        })
+    }
+
+    /// Returns how many more card points the declarer needs for winning.
+    ///
+    /// With _Schneider_ announced, the defenders must be made _Schneider_.
+    /// Returns [`None`] if this is already reached or out of reach or if not
+    /// playing a normal game.
+    fn declarer_points_needed(&self) -> Option<u8> {
+        let thresholds = self.options.point_thresholds;
+        if self.declaration.is_schneider() {
+            self.points_needed(Card::POINTS_TOTAL - thresholds.schneider)
+        } else {
+            self.points_needed(thresholds.winning)
+        }
+    }
+
+    /// Returns how many more card points the declarer needs to escape
+    /// _Schneider_ like [`Self::declarer_points_needed()`].
+    fn declarer_points_needed_against_schneider(&self) -> Option<u8> {
+        self.points_needed(self.options.point_thresholds.schneider + 1)
+    }
+
+    /// Returns how many more card points the declarer needs for reaching
+    /// `target` if still possible.
+    fn points_needed(&self, target: u8) -> Option<u8> {
+        let (declarer, _, open) = self.party_points()?;
+        (declarer < target && declarer + open >= target).then(|| target - declarer)
+    }
+
+    /// Derives the declarer's score when the game is over.
     ///
     /// # Panics
     /// Panics if not in [`GameState::Playing`].
-    fn calculate_points(&self) -> i16 {
+    fn game_value_breakdown(&self) -> GameValueBreakdown {
         let GameState::Playing(ref state) = self.state else {panic!("can only determine winner is state playing")};
 
+        let mut breakdown = GameValueBreakdown {
+            declaration: self.declaration,
+            base: self
+                .declaration
+                .value(&self.options.null_values)
+                .try_into()
+                .unwrap(),
+            bid: self.bid.try_into().unwrap(),
+            matadors: 0,
+            hand: self.declaration.is_hand(),
+            schneider: false,
+            schneider_announced: false,
+            schwarz: false,
+            schwarz_announced: false,
+            ouvert: self.declaration.is_ouvert(),
+            spitze: self.spitze,
+            won: state.declarer_points.is_none(),
+            doublings: u8::from(state.kontra.is_some())
+                + u8::from(state.re)
+                + u8::from(self.options.bock),
+        };
         let Declaration::Normal(mode, _) = self.declaration else {
-            // No need to check overbidding as it is impossible for Null games.
-            let value: i16 = u16::from(self.declaration).try_into().unwrap();
-            if state.declarer_points.is_some() {
-                return -2 * value;
-            } else {
-                return value;
-            }
+            return breakdown;
         };
 
-        let won = state.declarer_points.unwrap_or_default() >= Self::POINTS_WINNING;
-        let looser_points = if won {
-            state.team_points
+        let thresholds = self.options.point_thresholds;
+        // The Skat always belongs to the declarer, even in Hand games.
+        let skat: u8 = self.cards.skat.iter_known().sum();
+        let declarer_points = state.declarer_points.unwrap_or_default() + skat;
+        let won = declarer_points >= thresholds.winning;
+        let (looser_points, looser_tricks) = if won {
+            (state.team_points.unwrap_or_default(), state.team_points)
         } else {
-            state.declarer_points
+            (declarer_points, state.declarer_points)
         };
-        let schneider = looser_points.unwrap_or_default() <= Self::POINTS_SCHNEIDER;
+        let schneider = looser_points <= thresholds.schneider;
+        // An Ouvert game implies announcing Schneider and Schwarz.
         let schneider_announced = self.declaration.is_schneider();
-        let schwarz = looser_points.is_none();
+        let schwarz = looser_tricks.is_none();
         let schwarz_announced = self.declaration.is_schwarz();
 
-        let matadors = Matadors::from_cards(
+        breakdown.matadors = Matadors::from_cards(
             self.cards.played[self.declarer as usize]
                 .iter()
                 .cloned()
                 .chain(self.cards.skat.iter_known()),
         )[mode];
-
-        let multiplier: i16 = 1i16
-            + i16::from(self.declaration.is_hand())
-            + i16::from(schneider || schneider_announced)
-            + i16::from(schneider_announced)
-            + i16::from(schwarz || schwarz_announced)
-            + i16::from(schwarz_announced)
-            + i16::from(self.declaration.is_ouvert())
-            + i16::from(matadors);
-        let value = i16::try_from(u16::from(self.declaration)).unwrap() * multiplier;
-        let bid = self.bid.try_into().unwrap();
-        if won
+        // The last trick was won by the declarer with the lowest trump.
+        let spitze = self.cards.hands.iter().all(|h| h.is_empty())
+            && state.player == self.declarer
+            && self.cards.played[self.declarer as usize].last() == Some(&mode.lowest_trump());
+
+        let achieved_counts = !self.options.extras_count_only_if_announced;
+        breakdown.schneider = (schneider && achieved_counts) || schneider_announced;
+        breakdown.schneider_announced = schneider_announced;
+        breakdown.schwarz = (schwarz && achieved_counts) || schwarz_announced;
+        breakdown.schwarz_announced = schwarz_announced;
+        breakdown.won = won
             && (!schneider_announced || schneider)
             && (!schwarz_announced || schwarz)
-            && value >= bid
-        {
-            value
-        } else {
-            -2 * value.max(bid)
-        }
+            && (!self.spitze || spitze);
+        breakdown
     }
-}
 
-impl PartialEq for Skat {
-    fn eq(&self, other: &Self) -> bool {
-        todo!()
-    }
-}
+    /// Recomputes the value of the declared game from the declarer's cards.
+    ///
+    /// This assumes that the game is won without any unannounced extras.
+    /// Defenders can use this after the declarer of an _Ouvert_ game revealed
+    /// their hand to check whether the game actually reaches the bid, see
+    /// [`GameValueBreakdown::overbid()`].
+    /// Fails if the game is neither being revealed nor played or if the
+    /// relevant cards of the declarer are not all known.
+    #[cfg_attr(not(test), allow(dead_code))]
+    fn verify_game_value(&self) -> Result<GameValueBreakdown> {
+        let announced = match &self.state {
+            GameState::Revealing(_) => 0,
+            GameState::Playing(state) => u8::from(state.kontra.is_some()) + u8::from(state.re),
+            _ => return Err(SkatError::NotPlaying.into()),
+        };
+        let matadors = match self.declaration {
+            Declaration::Normal(mode, _) => {
+                self.calculate_matadors().ok_or(SkatError::HandHidden)?[mode]
+            }
+            _ => 0,
+        };
 
-impl Eq for Skat {}
+        let schneider = self.declaration.is_schneider();
+        let schwarz = self.declaration.is_schwarz();
+        Ok(GameValueBreakdown {
+            declaration: self.declaration,
+            base: self
+                .declaration
+                .value(&self.options.null_values)
+                .try_into()
+                .unwrap(),
+            bid: self.bid.try_into().unwrap(),
+            matadors,
+            hand: self.declaration.is_hand(),
+            schneider,
+            schneider_announced: schneider,
+            schwarz,
+            schwarz_announced: schwarz,
+            ouvert: self.declaration.is_ouvert(),
+            spitze: self.spitze,
+            won: true,
+            doublings: announced + u8::from(self.options.bock),
+        })
+    }
 
-impl Default for Skat {
-    fn default() -> Self {
-        Self {
-            cards: Default::default(),
-            bid: Self::MINIMUM_BID - 1,
-            // This will be overridden in the bidding phase anyway.
-            declarer: Player::Forehand,
-            declaration: Default::default(),
-            state: Default::default(),
+    /// Returns the _Kontra_ or _Re_ move the player at turn can make if any.
+    ///
+    /// Defenders can announce _Kontra_ before playing their first card.
+    /// The declarer can answer with _Re_ before playing their next card.
+    fn announcement(&self) -> Option<move_code> {
+        let GameState::Playing(state) = &self.state else {
+            return None;
+        };
+        if !self.options.kontra_re || state.ramsch.is_some() {
+            return None;
+        }
+        let played = CardStruct::HAND_SIZE - self.cards[state.player].len();
+        match state.kontra {
+            None if state.player != self.declarer && played == 0 => Some(Self::KONTRA),
+            // Kontra is announced in the first trick, possibly after the
+            // declarer played a card.
+            Some(kontra)
+                if state.player == self.declarer
+                    && !state.re
+                    && played == usize::from((self.declarer as u8) < (kontra as u8)) =>
+            {
+                Some(Self::RE)
+            }
+            _ => None,
         }
     }
-}
 
-impl GameMethods for Skat {
-    type Move = MoveCode;
+    /// Calculates the scores of all players if the game ended right now.
+    ///
+    /// In normal games, the known Skat cards count for the declarer.
+    /// Only the declarer can score points.
+    #[cfg_attr(not(test), allow(dead_code))]
+    fn compute_all_scores_if_game_ended_now(&self) -> Result<[i16; Player::COUNT]> {
+        let GameState::Playing(state) = &self.state else {
+            return Err(SkatError::NotPlaying.into());
+        };
+        if let Some(ramsch) = &state.ramsch {
+            return Ok(ramsch.scores(self.pushes));
+        }
 
-    fn create(init_info: &GameInit) -> Result<Self> {
-        Ok(match init_info {
-            GameInit::Default => Self::default(),
-            GameInit::Standard {
-                opts,
-                legacy,
-                state,
-            } => todo!(),
-            GameInit::Serialized(_) => todo!(),
-        })
+        let mut scores = [0; Player::COUNT];
+        scores[self.declarer as usize] = self.game_value_breakdown().points();
+        Ok(scores)
     }
 
-    fn copy_from(&mut self, other: &mut Self) -> Result<()> {
-        // FIXME: Reuse allocation or avoid dynamic allocations.
-        *self = other.clone();
+    /// Writes the string representation of the move `md` to `buf`.
+    fn write_move(&self, md: move_code, buf: &mut impl fmt::Write) -> Result<()> {
+        match self.state {
+            GameState::Dealing | GameState::Picking | GameState::Putting => {
+                let card: OptCard = md.try_into()?;
+                write!(buf, "{card}")
+            }
+            GameState::Bidding { state } => {
+                #[allow(clippy::assertions_on_constants)]
+                const _: () = assert!(1 < Skat::MAXIMUM_BID);
+
+                if md == 0 {
+                    write!(buf, "pass")
+                } else if md == 1 && state == BiddingState::Forehand {
+                    write!(buf, "{}", Self::MINIMUM_BID)
+                } else if md == 1 {
+                    write!(buf, "accept")
+                } else {
+                    write!(buf, "{}", md)
+                }
+            }
+            GameState::SkatDecision if md == 0 && self.is_ramsch() => write!(buf, "push"),
+            GameState::SkatDecision if md == 0 => write!(buf, "Hand"),
+            GameState::SkatDecision => write!(buf, "pick"),
+            GameState::Declaring => {
+                let declaration: DeclarationMove = md.try_into()?;
+                write!(buf, "{declaration}")
+            }
+            GameState::Playing(_) if md == Self::KONTRA => write!(buf, "Kontra"),
+            GameState::Playing(_) if md == Self::RE => write!(buf, "Re"),
+            GameState::Revealing(_) | GameState::Playing(_) => {
+                let card: Card = md.try_into()?;
+                write!(buf, "{card}")
+            }
+            GameState::Finished(_) => return Err(SkatError::GameOver.into()),
+        }
+        .expect("writing move failed");
         Ok(())
     }
 
-    fn player_count(&mut self) -> Result<u8> {
-        Ok(Player::COUNT.try_into().unwrap())
+    /// Describes the move `md` in the current state as a sentence for
+    /// tooltips.
+    #[cfg_attr(not(test), allow(dead_code))]
+    pub fn explain_move(&self, md: move_code) -> Result<String> {
+        Ok(match self.state {
+            GameState::Dealing => match OptCard::try_from(md)? {
+                OptCard::Known(card) => format!("Deal the {}", EnglishCard(card)),
+                OptCard::Hidden => "Deal a hidden card".to_string(),
+            },
+            GameState::Bidding { .. } if md == 0 => "Pass".to_string(),
+            GameState::Bidding {
+                state: BiddingState::Forehand,
+            } if md == 1 => format!("Play at {}", Self::MINIMUM_BID),
+            GameState::Bidding { .. } if md == 1 => "Accept the bid".to_string(),
+            GameState::Bidding { .. } => format!("Raise the bid to {md}"),
+            GameState::SkatDecision if md == 0 && self.is_ramsch() => {
+                "Push the Skat unseen".to_string()
+            }
+            GameState::SkatDecision if md == 0 => "Play a Hand game".to_string(),
+            GameState::SkatDecision => "Pick up the Skat".to_string(),
+            GameState::Picking => match OptCard::try_from(md)? {
+                OptCard::Known(card) => format!("Pick up the {}", EnglishCard(card)),
+                OptCard::Hidden => "Pick up a hidden card".to_string(),
+            },
+            GameState::Putting => match OptCard::try_from(md)? {
+                OptCard::Known(card) => format!(
+                    "Put the {} into the Skat ({} points)",
+                    EnglishCard(card),
+                    card.points()
+                ),
+                OptCard::Hidden => "Put a hidden card into the Skat".to_string(),
+            },
+            GameState::Declaring => match DeclarationMove::try_from(md)? {
+                DeclarationMove::Declare(declaration) => match self.calculate_matadors() {
+                    Some(matadors) => format!(
+                        "Declare {declaration} (value {})",
+                        declaration.safe_value(&matadors, &self.options.null_values)
+                    ),
+                    None => format!("Declare {declaration}"),
+                },
+                DeclarationMove::Overbidden => "Admit being overbidden".to_string(),
+                DeclarationMove::Spitze => "Announce Spitze".to_string(),
+            },
+            GameState::Revealing(_) => {
+                format!("Reveal the {}", EnglishCard(Card::try_from(md)?))
+            }
+            GameState::Playing(_) if md == Self::KONTRA => "Announce Kontra".to_string(),
+            GameState::Playing(_) if md == Self::RE => "Announce Re".to_string(),
+            GameState::Playing(_) => {
+                let card = Card::try_from(md)?;
+                format!("Play the {} ({} points)", EnglishCard(card), card.points())
+            }
+            GameState::Finished(_) => return Err(SkatError::GameOver.into()),
+        })
     }
 
-    fn import_state(&mut self, string: Option<&str>) -> Result<()> {
-        todo!()
-    }
+    /// Returns the legal moves of the player to move as strings.
+    ///
+    /// This is empty if nobody is to move.
+    #[cfg_attr(not(test), allow(dead_code))]
+    fn legal_moves_str(&self) -> Result<Vec<String>> {
+        let mut players = vec![];
+        self.clone().players_to_move(&mut players)?;
+        if players.is_empty() {
+            return Ok(vec![]);
+        }
 
-    fn export_state(
-        &mut self,
-        player: player_id,
-        str_buf: &mut mirabel::ValidCString,
-    ) -> Result<()> {
-        todo!()
+        let mut moves: Vec<move_code> = vec![];
+        self.concrete_moves(&mut moves)?;
+        moves
+            .into_iter()
+            .map(|md| {
+                let mut buf = String::new();
+                self.write_move(md, &mut buf)?;
+                Ok(buf)
+            })
+            .collect()
     }
 
-    fn players_to_move(&mut self, players: &mut Vec<player_id>) -> Result<()> {
-        players.push(match self.state {
-            GameState::Dealing | GameState::Picking | GameState::Revealing(_) => PLAYER_RAND,
-            GameState::Bidding { state } => state.source().into(),
-            GameState::SkatDecision | GameState::Putting | GameState::Declaring => {
-                self.declarer.into()
+    /// Apply the move `md` of `player` and resolve forced moves if enabled.
+    fn play(&mut self, player: player_id, md: move_code) -> SkatResult<()> {
+        self.apply_move(player, md)?;
+        #[cfg(feature = "history")]
+        self.history.record(player, md);
+        if self.options.auto_resolve {
+            while let Some((player, md)) = self.forced_move() {
+                self.apply_move(player, md)?;
             }
-            GameState::Playing(ref state) => state.player.into(),
-            GameState::Finished(_) => return Ok(()),
-        });
+        }
+        debug_assert!(
+            self.cards.counts().within_caps(),
+            "too many cards at one location"
+        );
+        if let GameState::Playing(state) = &self.state {
+            if state.ramsch.is_none() {
+                let trick: u8 = self.cards.trick.iter().copied().sum();
+                let skat: u8 = self.cards.skat.iter_known().sum();
+                debug_assert_eq!(
+                    state.total_points_seen()
+                        + skat
+                        + trick
+                        + self.cards.points_remaining(self.declaration),
+                    Card::POINTS_TOTAL,
+                    "tallies do not match the captured cards"
+                );
+            }
+        }
         Ok(())
     }
 
-    fn get_concrete_moves(&mut self, player: player_id, moves: &mut Vec<Self::Move>) -> Result<()> {
-        match self.state {
-            GameState::Dealing => moves.extend(
-                self.cards
-                    .iter_unknown()
-                    .map(|card| MoveCode::from(OptCard::from(card))),
-            ),
-            GameState::Bidding { state } => {
-                // 0 means passing.
-                moves.push(0.into());
-                if state.respond() {
-                    // 1 means accepting.
-                    moves.push(1.into());
-                } else {
-                    moves.extend(
-                        (self.bid.saturating_add(1)..=Self::MAXIMUM_BID)
-                            .map(move_code::from)
-                            .map(MoveCode::from),
-                    );
-                }
-            }
-            GameState::SkatDecision => moves.extend_from_slice(&[0.into(), 1.into()]),
-            GameState::Picking => match self.cards.skat.last() {
-                Some(OptCard::Known(card)) => moves.push(OptCard::from(*card).into()),
-                Some(OptCard::Hidden) => moves.extend(
-                    self.cards
-                        .iter_unknown()
-                        .map(|card| MoveCode::from(OptCard::from(card))),
-                ),
-                None => {
-                    return Err(Error::new_static(
-                        ErrorCode::InvalidState,
-                        "no card in the Skat to pick up\0",
-                    ))
-                }
-            },
-            GameState::Putting => {
-                let hand = &self.cards[self.declarer];
-                moves.extend(
-                    hand.iter_known()
-                        .map(|card| MoveCode::from(OptCard::from(card))),
-                );
-                if hand.iter().any(|card| matches!(card, OptCard::Hidden)) {
-                    moves.extend(
-                        self.cards
-                            .iter_unknown()
-                            .map(|card| MoveCode::from(OptCard::from(card))),
-                    )
-                }
-            }
-            GameState::Declaring => {
-                let matadors = self.calculate_matadors();
-                moves.extend(
-                    Declaration::all(self.declaration.is_hand())
-                        .into_iter()
-                        .filter(|d| {
-                            matadors
-                                .as_ref()
-                                .filter(|m| d.allowed(self.bid, m))
-                                .is_some()
-                        })
-                        .map(|d| MoveCode::from(DeclarationMove::Declare(d))),
+    /// Parses, validates, and applies the move `string` of `player`.
+    ///
+    /// `self` is left untouched if the move cannot be parsed or is illegal.
+    #[cfg_attr(not(test), allow(dead_code))]
+    pub fn play_str(&mut self, player: player_id, string: &str) -> SkatResult<()> {
+        let md = self.parse_move(string)?;
+        self.check_move(player, md)?;
+        self.play(player, md)
+    }
+
+    /// Replay the move strings of a game transcript, e.g., from a surena log.
+    ///
+    /// Every move is made by the first player to move.
+    /// Returns the final state or the index of the first invalid move together
+    /// with the reason.
+    #[cfg_attr(not(test), allow(dead_code))]
+    pub fn validate_transcript(init: &GameInit, moves: &[&str]) -> Result<Self> {
+        let mut game = Self::create(init)?;
+        for (i, string) in moves.iter().enumerate() {
+            let Some(player) = game.state.current_player(game.declarer) else {
+                return Err(SkatError::TranscriptMove(i, Box::new(SkatError::GameOver)).into());
+            };
+            game.play_str(player, string)
+                .map_err(|e| SkatError::TranscriptMove(i, Box::new(e)))?;
+        }
+        Ok(game)
+    }
+
+    /// Replays a transcript written by [`Self::to_notation()`].
+    ///
+    /// The result line is ignored.
+    #[cfg_attr(not(test), allow(dead_code))]
+    pub fn from_notation(init: &GameInit, notation: &str) -> Result<Self> {
+        let mut moves = vec![];
+        for line in notation.lines().filter(|l| !l.trim().is_empty()) {
+            let Some((label, rest)) = line.split_once(':') else {
+                return Err(
+                    SkatError::Parse("notation", format!("missing phase in: {line}")).into(),
                 );
-                if moves.is_empty() {
-                    moves.push(DeclarationMove::Overbidden.into());
-                }
+            };
+            if label.trim() != "result" {
+                moves.extend(rest.split(',').map(str::trim));
             }
-            GameState::Revealing(i) => {
-                let card = self.cards[self.declarer]
-                    .get(i)
-                    .ok_or_else(|| reveal_error(i))?;
-                match *card {
-                    OptCard::Known(c) => moves.push(c.into()),
-                    OptCard::Hidden => {
-                        moves.extend(self.cards.iter_unknown().map(Into::<MoveCode>::into))
-                    }
-                }
+        }
+        Self::validate_transcript(init, &moves)
+    }
+
+    /// Replays the lines written by [`Self::export_to_replay_format()`].
+    #[cfg_attr(not(test), allow(dead_code))]
+    pub fn from_replay_format(init: &GameInit, replay: &str) -> Result<Self> {
+        let mut game = Self::create(init)?;
+        for (i, line) in replay.lines().filter(|l| !l.trim().is_empty()).enumerate() {
+            let (name, string) = line.trim().split_once(' ').unwrap_or((line.trim(), ""));
+            let player = match name {
+                "fore" => Player::Forehand.into(),
+                "mid" => Player::Middlehand.into(),
+                "rear" => Player::Rearhand.into(),
+                "rand" => PLAYER_RAND,
+                _ => return Err(SkatError::TranscriptMove(i, Box::new(SkatError::NoSeat)).into()),
+            };
+            if game.state.current_player(game.declarer) != Some(player) {
+                return Err(SkatError::TranscriptMove(i, Box::new(SkatError::NotYourTurn)).into());
             }
-            GameState::Playing(ref state) => moves.extend(
-                self.cards
-                    .allowed(state.player, self.declaration)
-                    .into_iter()
-                    .map(Into::<MoveCode>::into),
-            ),
-            GameState::Finished(_) => todo!(),
+            game.play_str(player, string)
+                .map_err(|e| SkatError::TranscriptMove(i, Box::new(e)))?;
         }
+        Ok(game)
+    }
 
-        Ok(())
+    /// Count the move sequences of length `depth` reachable from `self`.
+    ///
+    /// Chance nodes are expanded over all their concrete outcomes and finished
+    /// games count as a single sequence.
+    /// This is meant for validating the move generation.
+    #[cfg_attr(not(test), allow(dead_code))]
+    fn perft(&self, depth: usize) -> Result<u64> {
+        if depth == 0 {
+            return Ok(1);
+        }
+        let mut origin = self.clone();
+        let Some(player) = self.state.current_player(self.declarer) else {
+            return Ok(1);
+        };
+
+        let mut moves: Vec<move_code> = vec![];
+        self.concrete_moves(&mut moves)?;
+        let mut game = self.clone();
+        let mut count = 0;
+        for md in moves {
+            game.copy_from(&mut origin)?;
+            game.play(player, md)?;
+            count += game.perft(depth - 1)?;
+        }
+        Ok(count)
     }
 
-    /// Convert a move string to a [`MoveCode`].
+    /// Returns strength indicators of the hand of `player` for bidding.
     ///
-    /// Examples for dealing cards: `10S` for _10 of spades_ or `?` for a hidden
-    /// action.
-    fn get_move_data(&mut self, _player: player_id, string: &str) -> Result<Self::Move> {
-        let string = string.trim();
-        match self.state {
-            GameState::Dealing | GameState::Picking | GameState::Putting => {
-                let card: OptCard = string.parse()?;
-                Ok(card.into())
-            }
-            GameState::Bidding { state: _ } => {
-                if string.eq_ignore_ascii_case("pass") {
-                    Ok(0.into())
-                } else if string.eq_ignore_ascii_case("accept")
-                    || string.eq_ignore_ascii_case("yes")
-                {
-                    Ok(1.into())
-                } else {
-                    string.parse().map(move_code::into).map_err(|e| {
-                        Error::new_dynamic(
-                            ErrorCode::InvalidInput,
-                            format!("failed to parse move as a valid number: {e}"),
-                        )
-                    })
-                }
-            }
-            GameState::SkatDecision => {
-                if string.eq_ignore_ascii_case("hand") {
-                    Ok(0.into())
-                } else if string.eq_ignore_ascii_case("pick") {
-                    Ok(1.into())
-                } else {
-                    Err(Error::new_static(
-                        ErrorCode::InvalidInput,
-                        "invalid Skat decision\0",
-                    ))
-                }
-            }
-            GameState::Declaring => {
-                let declaration: DeclarationMove = string.parse()?;
-                Ok(declaration.into())
+    /// Fails if the hand contains hidden cards.
+    #[cfg_attr(not(test), allow(dead_code))]
+    pub(crate) fn hand_metrics(&self, player: Player) -> Result<HandMetrics> {
+        let hand = &self.cards[player];
+        if hand.hidden_count() > 0 {
+            return Err(SkatError::HandHidden.into());
+        }
+        Ok(HandMetrics::from_cards(hand.iter_known()))
+    }
+
+    /// Returns the highest bid `player` can safely make with their hand.
+    ///
+    /// This is the best value of the normal games neither played _Hand_ nor
+    /// won _Schneider_ assuming the Skat does not change the matadors.
+    /// Only games in which `player` holds at least half of the trumps are
+    /// considered and `0` is returned if there is none.
+    /// Fails if the hand contains hidden cards.
+    #[cfg_attr(not(test), allow(dead_code))]
+    pub(crate) fn estimate_bid_strength(&self, player: Player) -> Result<u16> {
+        let hand = &self.cards[player];
+        if hand.hidden_count() > 0 {
+            return Err(SkatError::HandHidden.into());
+        }
+        let cards: CardSet = hand.iter_known().collect();
+        let matadors = Matadors::from_cards(cards.iter());
+        Ok(NormalMode::all()
+            .into_iter()
+            .map(|mode| Declaration::Normal(mode, GameLevel::Normal))
+            .filter(|&declaration| {
+                let trumps = Card::all()
+                    .into_iter()
+                    .collect::<CardSet>()
+                    .trumps_under(declaration);
+                2 * cards.trumps_under(declaration).len() >= trumps.len()
+            })
+            .map(|declaration| declaration.safe_value(&matadors, &self.options.null_values))
+            .max()
+            .unwrap_or(0))
+    }
+
+    /// Checks whether the declarer may declare `declaration` now.
+    ///
+    /// Overbidding is only detected if all cards of the declarer are known.
+    fn validate_declaration(&self, declaration: Declaration) -> SkatResult<()> {
+        if matches!(declaration, Declaration::Ramsch) {
+            return Err(SkatError::RamschDeclared);
+        }
+        if declaration.is_schneider() && !self.declaration.is_hand() {
+            return Err(SkatError::LevelRequiresHand);
+        }
+        if declaration.is_hand() != self.declaration.is_hand() {
+            return Err(if declaration.is_hand() {
+                SkatError::HandAfterPicking
+            } else {
+                SkatError::HandRequired
+            });
+        }
+        if self.spitze {
+            let Declaration::Normal(mode, _) = declaration else {
+                return Err(SkatError::SpitzeInNull);
+            };
+            if !self.holds_lowest_trump(mode) {
+                return Err(SkatError::SpitzeCardMissing);
             }
-            GameState::Revealing(_) | GameState::Playing(_) => {
-                let card: Card = string.parse()?;
-                Ok(card.into())
+        }
+        if let Some(matadors) = self.calculate_matadors() {
+            if !declaration.allowed(self.bid, &matadors, &self.options.null_values) {
+                return Err(SkatError::Overbidding);
             }
-            GameState::Finished(_) => todo!(),
         }
+        Ok(())
     }
 
-    fn get_move_str(
-        &mut self,
-        player: player_id,
-        mov: MoveDataSync<<Self::Move as MoveData>::Rust<'_>>,
-        str_buf: &mut mirabel::ValidCString,
-    ) -> Result<()> {
-        match self.state {
-            GameState::Dealing | GameState::Picking | GameState::Putting => {
-                let card: OptCard = mov.md.try_into()?;
-                write!(str_buf, "{card}")
-            }
-            GameState::Bidding { state: _ } => {
-                #[allow(clippy::assertions_on_constants)]
-                const _: () = assert!(1 < Skat::MAXIMUM_BID);
+    /// Does the declarer (possibly) hold the lowest trump of `mode`.
+    ///
+    /// Hidden cards could be this trump.
+    fn holds_lowest_trump(&self, mode: NormalMode) -> bool {
+        self.cards[self.declarer]
+            .iter()
+            .any(|c| matches!(c, OptCard::Hidden) || *c == mode.lowest_trump().into())
+    }
 
-                if mov.md == 0 {
-                    write!(str_buf, "pass")
-                } else if mov.md == 1 {
-                    write!(str_buf, "accept")
-                } else {
-                    write!(str_buf, "{}", mov.md)
+    /// Is there any game the declarer can still declare after announcing
+    /// _Spitze_.
+    fn spitze_possible(&self) -> bool {
+        Declaration::all(self.declaration.is_hand())
+            .into_iter()
+            .any(|d| match d {
+                Declaration::Normal(mode, _) => {
+                    self.holds_lowest_trump(mode) && self.validate_declaration(d).is_ok()
                 }
-            }
-            GameState::SkatDecision if mov.md == 0 => write!(str_buf, "Hand"),
-            GameState::SkatDecision => write!(str_buf, "pick"),
-            GameState::Declaring => {
-                let declaration: DeclarationMove = mov.md.try_into()?;
-                write!(str_buf, "{declaration}")
-            }
-            GameState::Revealing(_) | GameState::Playing(_) => {
-                let card: Card = mov.md.try_into()?;
-                write!(str_buf, "{card}")
-            }
-            GameState::Finished(_) => todo!(),
+                _ => false,
+            })
+    }
+
+    /// Checks that the declarer's hand is fully known after revealing it.
+    ///
+    /// A hidden card left at this point is a bug, so this also panics in
+    /// debug builds.
+    fn null_ouvert_reveal_check(&self) -> SkatResult<()> {
+        let hidden = self.cards[self.declarer].hidden_count();
+        debug_assert_eq!(0, hidden, "declarer's hand is not fully revealed");
+        if hidden > 0 {
+            return Err(SkatError::UnrevealedHand);
         }
-        .expect("writing move failed");
         Ok(())
     }
 
-    fn make_move(
-        &mut self,
-        player: player_id,
-        mov: MoveDataSync<<Self::Move as MoveData>::Rust<'_>>,
-    ) -> Result<()> {
+    /// Apply the move `md` of `player` without any auto-resolution.
+    fn apply_move(&mut self, player: player_id, md: move_code) -> SkatResult<()> {
+        let ramsch = self.is_ramsch();
         match &mut self.state {
             GameState::Dealing => {
-                assert_eq!(PLAYER_RAND, player);
-                let card = mov.md.try_into()?;
+                if player != PLAYER_RAND {
+                    return Err(SkatError::DealerNotRandom);
+                }
+                let card = md.try_into()?;
                 let dealt = self.cards.count();
-                let target = deal_to(dealt);
+                let target = deal_to(dealt)?;
                 self.cards.give(target, card);
                 if usize::from(dealt) + 1 >= Card::COUNT {
                     self.state = GameState::Bidding {
-                        state: Default::default(),
+                        state: BiddingState::first(self.options.bidding_order),
                     };
                 }
             }
             GameState::Bidding { state } => {
                 let any_bid = self.bid >= Self::MINIMUM_BID;
-                let next = match mov.md {
-                    0 => state.next(true, any_bid),
-                    1 => state.next(false, any_bid),
+                let order = self.options.bidding_order;
+                let next = match md {
+                    0 => state.next(true, any_bid, order),
+                    1 => {
+                        if matches!(state, BiddingState::Forehand) {
+                            // Nobody has bid yet, so the forehand plays for
+                            // the minimum bid.
+                            self.bid = self.bid.max(Self::MINIMUM_BID);
+                        }
+                        state.next(false, any_bid, order)
+                    }
                     m => {
                         self.bid = m.try_into().expect("bid overflowed");
-                        state.next(false, any_bid)
+                        state.next(false, any_bid, order)
                     }
                 };
-                match next {
-                    BiddingResult::Continue(s) => *state = s,
-                    BiddingResult::Finished(p) => {
-                        self.declarer = p;
-                        self.state = GameState::SkatDecision
+                if let BiddingResult::Continue(s) = next {
+                    *state = s;
+                } else if let Some(p) = next.declarer() {
+                    self.declarer = p;
+                    self.state = GameState::SkatDecision;
+                } else if self.options.ramsch_on_all_pass {
+                    self.declaration = Declaration::Ramsch;
+                    if self.options.ramsch_schieben {
+                        // The declarer is just the player handling the Skat
+                        // here.
+                        self.declarer = Player::Forehand;
+                        self.state = GameState::SkatDecision;
+                    } else {
+                        self.start_ramsch();
                     }
-                    BiddingResult::Draw => self.state = GameState::Finished(Default::default()),
+                } else {
+                    debug_assert!(next.is_draw());
+                    self.state = GameState::Finished(Default::default());
                 }
             }
-            GameState::SkatDecision if mov.md == 0 => {
+            GameState::SkatDecision if md == 0 && ramsch => {
+                self.pushes += 1;
+                self.push_skat();
+            }
+            GameState::SkatDecision if md == 0 => {
                 // Change the game to a _Hand_ game to encode that the declarer
                 // is playing _Hand_.
                 self.declaration = Declaration::NullHand;
                 self.state = GameState::Declaring;
             }
-            GameState::SkatDecision => self.state = GameState::Picking,
+            GameState::SkatDecision => {
+                self.picker = Some(self.declarer);
+                self.state = GameState::Picking;
+            }
             GameState::Picking => {
-                assert_eq!(PLAYER_RAND, player);
-                let card = mov.md.try_into()?;
+                if player != PLAYER_RAND {
+                    return Err(SkatError::PickerNotRandom);
+                }
+                let card = md.try_into()?;
                 self.cards.skat.pop();
                 self.cards.give(Some(self.declarer), card);
                 if self.cards.skat.is_empty() {
@@ -668,19 +1683,34 @@ impl GameMethods for Skat {
                 }
             }
             GameState::Putting => {
-                let card = mov.md.try_into()?;
+                let card = md.try_into()?;
+                // The declarer must end up with a full hand after putting the
+                // last card, which imported states might violate.
+                let skat = self.cards.skat.len() + 1;
+                if skat > CardStruct::SKAT_SIZE
+                    || (skat == CardStruct::SKAT_SIZE
+                        && self.cards[self.declarer].len() != CardStruct::HAND_SIZE + 1)
+                {
+                    return Err(SkatError::HandSizeMismatch);
+                }
                 self.cards.take(self.declarer, card)?;
                 self.cards.give(None, card);
-                if self.cards.skat.len() >= CardStruct::SKAT_SIZE {
-                    self.state = GameState::Declaring;
+                if skat == CardStruct::SKAT_SIZE {
+                    if ramsch {
+                        self.push_skat();
+                    } else {
+                        self.state = GameState::Declaring;
+                    }
                 }
             }
             GameState::Declaring => {
-                let declaration: DeclarationMove = mov.md.try_into()?;
+                let declaration: DeclarationMove = md.try_into()?;
                 match declaration {
                     DeclarationMove::Declare(declaration) => {
                         self.declaration = declaration;
                         self.state = if declaration.is_ouvert() {
+                            // Reveal the cards in a predictable order.
+                            self.cards[self.declarer].sort_for_declaration(declaration);
                             // This assumes that the declarer has at least one
                             // card.
                             GameState::Revealing(0)
@@ -691,19 +1721,23 @@ impl GameMethods for Skat {
                     DeclarationMove::Overbidden => {
                         self.state = GameState::Finished(self.declarer.others().to_vec())
                     }
+                    DeclarationMove::Spitze => self.spitze = true,
                 }
             }
             GameState::Revealing(i) => {
-                let card: Card = mov.md.try_into()?;
+                let card: Card = md.try_into()?;
                 let hand = &mut self.cards[self.declarer];
                 *hand.get_mut(*i).ok_or_else(|| reveal_error(*i))? = OptCard::Known(card);
                 *i += 1;
                 if *i >= hand.len() {
+                    self.null_ouvert_reveal_check()?;
                     self.state = GameState::Playing(Default::default())
                 }
             }
+            GameState::Playing(state) if md == Self::KONTRA => state.kontra = Some(state.player),
+            GameState::Playing(state) if md == Self::RE => state.re = true,
             GameState::Playing(state) => 'p: {
-                let card: Card = mov.md.try_into()?;
+                let card: Card = md.try_into()?;
                 self.cards.take(state.player, OptCard::Known(card))?;
                 let trick = &mut self.cards.trick;
                 trick.push(card);
@@ -712,13 +1746,15 @@ impl GameMethods for Skat {
                     break 'p;
                 }
 
-                let w = self.cards.winner(self.declaration);
-                let mut winner = state.player;
-                for _ in 0..w {
-                    winner = winner.next();
-                }
+                let (_, winner) = self
+                    .cards
+                    .current_trick_leader(state.player, self.declaration)
+                    .expect("trick is complete");
                 let points: u8 = self.cards.trick.iter().cloned().sum();
-                if winner == self.declarer {
+                if let Some(ramsch) = &mut state.ramsch {
+                    ramsch.trick_points[winner as usize] += points;
+                    ramsch.tricks_won[winner as usize] += 1;
+                } else if winner == self.declarer {
                     *state.declarer_points.get_or_insert(0) += points;
                 } else {
                     *state.team_points.get_or_insert(0) += points;
@@ -726,66 +1762,244 @@ impl GameMethods for Skat {
                 self.cards.put_trick(state.player);
                 state.player = winner;
 
-                // TODO: Calculate overall winner.
                 if (self.declaration.is_null() && state.declarer_points.is_some())
                     || (self.declaration.is_schwarz() && state.team_points.is_some())
                     || self.cards.hands.iter().all(|h| h.is_empty())
                 {
-                    // TODO: Send Skat to players.
-                    let points = self.calculate_points();
+                    let winners = if let Some(mut ramsch) = state.ramsch.take() {
+                        // The Skat goes to the winner of the last trick.
+                        // Its points are the ones not taken in tricks as it
+                        // might be hidden from this point of view.
+                        let taken: u8 = ramsch.trick_points.iter().sum();
+                        ramsch.trick_points[winner as usize] += Card::POINTS_TOTAL - taken;
+                        let winners = ramsch.winners();
+                        self.ramsch_result = Some(RamschResult::new(ramsch, self.pushes));
+                        winners
+                    } else {
+                        let breakdown = self.game_value_breakdown();
+                        self.breakdown = Some(breakdown);
+                        if breakdown.points() > 0 {
+                            // This includes Null games in which the declarer
+                            // did not take any trick.
+                            vec![self.declarer]
+                        } else {
+                            self.declarer.others().to_vec()
+                        }
+                    };
+                    if self.options.reveal_skat_after_game {
+                        self.cards.reveal_skat();
+                    }
+                    self.state = GameState::Finished(winners);
                 }
             }
-            GameState::Finished(_) => todo!(),
+            GameState::Finished(_) => return Err(SkatError::GameOver),
         }
 
         Ok(())
     }
 
-    fn get_results(&mut self, players: &mut Vec<player_id>) -> Result<()> {
-        todo!()
+    /// Returns the player and the only possible move if the current step is
+    /// forced.
+    ///
+    /// This is the case when picking up or revealing a known card.
+    fn forced_move(&self) -> Option<(player_id, move_code)> {
+        match self.state {
+            GameState::Picking => match self.cards.skat.last() {
+                Some(&OptCard::Known(card)) => Some((PLAYER_RAND, card.into())),
+                _ => None,
+            },
+            GameState::Revealing(i) => match self.cards[self.declarer].get(i) {
+                Some(&OptCard::Known(card)) => Some((self.declarer.into(), card.into())),
+                _ => None,
+            },
+            _ => None,
+        }
     }
 
-    fn is_legal_move(
-        &mut self,
-        player: player_id,
-        mov: MoveDataSync<<Self::Move as MoveData>::Rust<'_>>,
-    ) -> Result<()> {
+    /// Generate all concrete moves for the player to move.
+    ///
+    /// This is generic over the move type to allow using raw [`move_code`]s
+    /// internally.
+    fn concrete_moves<M>(&self, moves: &mut Vec<M>) -> Result<()>
+    where
+        M: From<move_code> + From<OptCard> + From<Card> + From<DeclarationMove>,
+    {
         match self.state {
-            GameState::Dealing => {
-                if player != PLAYER_RAND {
-                    return Err(Error::new_static(
-                        ErrorCode::InvalidPlayer,
-                        "only PLAYER_RAND can deal cards\0",
-                    ));
-                }
-                let card = mov.md.try_into()?;
-                if let OptCard::Known(card) = card {
-                    if self.cards.iter().any(|c| c == card) {
-                        return Err(Error::new_static(
-                            ErrorCode::InvalidMove,
-                            "this card has already been dealt\0",
-                        ));
-                    }
-                }
-            }
+            GameState::Dealing => moves.extend(
+                self.cards
+                    .iter_unknown()
+                    .map(|card| M::from(OptCard::from(card))),
+            ),
+            GameState::Bidding { state } => {
+                // 0 means passing.
+                moves.push(0.into());
+                if state.respond() {
+                    // 1 means accepting or playing for the minimum bid in the
+                    // forehand state.
+                    moves.push(1.into());
+                } else if self.options.canonical_bids {
+                    moves.extend(
+                        self.next_valid_bid_after(self.bid)
+                            .map(move_code::from)
+                            .map(M::from),
+                    );
+                } else {
+                    moves.extend(
+                        self.legal_bids_for_call(self.bid)
+                            .map(move_code::from)
+                            .map(M::from),
+                    );
+                }
+            }
+            GameState::SkatDecision => moves.extend([0.into(), 1.into()]),
+            GameState::Picking => match self.cards.skat.last() {
+                Some(OptCard::Known(card)) => moves.push(OptCard::from(*card).into()),
+                Some(OptCard::Hidden) => moves.extend(
+                    self.cards
+                        .iter_unknown()
+                        .map(|card| M::from(OptCard::from(card))),
+                ),
+                None => return Err(SkatError::NoSkatCard.into()),
+            },
+            GameState::Putting => {
+                let hand = &self.cards[self.declarer];
+                moves.extend(hand.iter_known().map(|card| M::from(OptCard::from(card))));
+                if hand.iter().any(|card| matches!(card, OptCard::Hidden)) {
+                    moves.extend(
+                        self.cards
+                            .iter_unknown()
+                            .map(|card| M::from(OptCard::from(card))),
+                    )
+                }
+            }
+            GameState::Declaring => {
+                let mut declarations: Vec<_> = Declaration::all(self.declaration.is_hand())
+                    .into_iter()
+                    .filter(|&d| self.validate_declaration(d).is_ok())
+                    .collect();
+                // Offer the declarations from the weakest to the strongest.
+                declarations.sort_by_key(|d| d.sort_key(&self.options.null_values));
+                moves.extend(
+                    declarations
+                        .iter()
+                        .map(|&d| M::from(DeclarationMove::Declare(d))),
+                );
+                if declarations.is_empty() {
+                    moves.push(DeclarationMove::Overbidden.into());
+                } else if self.options.spitze && !self.spitze && self.spitze_possible() {
+                    moves.push(DeclarationMove::Spitze.into());
+                }
+            }
+            GameState::Revealing(i) => {
+                let card = self.cards[self.declarer]
+                    .get(i)
+                    .ok_or_else(|| reveal_error(i))?;
+                match *card {
+                    OptCard::Known(c) => moves.push(c.into()),
+                    OptCard::Hidden => moves.extend(self.cards.iter_unknown().map(M::from)),
+                }
+            }
+            GameState::Playing(ref state) => {
+                moves.extend(
+                    self.cards
+                        .allowed(state.player, self.declaration)
+                        .into_iter()
+                        .map(M::from),
+                );
+                moves.extend(self.announcement().map(M::from));
+            }
+            GameState::Finished(_) => {}
+        }
+
+        Ok(())
+    }
+
+    /// Convert a move `string` to its move code.
+    ///
+    /// See [`GameMethods::get_move_data()`] for the format.
+    fn parse_move(&self, string: &str) -> SkatResult<move_code> {
+        let string = string.trim();
+        match self.state {
+            GameState::Dealing | GameState::Picking | GameState::Putting => {
+                let card: OptCard = string.parse()?;
+                Ok(card.into())
+            }
+            GameState::Bidding { state } => {
+                if string.eq_ignore_ascii_case("pass") {
+                    Ok(0)
+                } else if string.eq_ignore_ascii_case("accept")
+                    || string.eq_ignore_ascii_case("yes")
+                {
+                    Ok(1)
+                } else if state == BiddingState::Forehand
+                    && (string.eq_ignore_ascii_case("play")
+                        || string == Self::MINIMUM_BID.to_string())
+                {
+                    // The forehand plays for the minimum bid.
+                    Ok(1)
+                } else {
+                    string
+                        .parse::<move_code>()
+                        .map_err(|e| SkatError::InvalidBidNumber(e.to_string()))
+                }
+            }
+            GameState::SkatDecision => {
+                let hand = if self.is_ramsch() { "push" } else { "hand" };
+                if string.eq_ignore_ascii_case(hand) {
+                    Ok(0)
+                } else if string.eq_ignore_ascii_case("pick") {
+                    Ok(1)
+                } else {
+                    Err(SkatError::InvalidSkatDecision)
+                }
+            }
+            GameState::Declaring => {
+                let declaration: DeclarationMove = string.parse()?;
+                Ok(declaration.into())
+            }
+            GameState::Playing(_) if string.eq_ignore_ascii_case("kontra") => Ok(Self::KONTRA),
+            GameState::Playing(_) if string.eq_ignore_ascii_case("re") => Ok(Self::RE),
+            GameState::Revealing(_) | GameState::Playing(_) => {
+                let card: Card = string.parse()?;
+                Ok(card.into())
+            }
+            GameState::Finished(_) => Err(SkatError::GameOver),
+        }
+    }
+
+    /// Check whether `player` may make the move `md`.
+    fn check_move(&self, player: player_id, md: move_code) -> SkatResult<()> {
+        match self.state {
+            GameState::Dealing => {
+                if player != PLAYER_RAND {
+                    return Err(SkatError::DealerNotRandom);
+                }
+                let card = md.try_into()?;
+                if let OptCard::Known(card) = card {
+                    if self.cards.iter().any(|c| c == card) {
+                        return Err(SkatError::CardAlreadyDealt);
+                    }
+                }
+            }
             GameState::Bidding { state } => {
-                if Player::try_from(player) != Ok(state.source()) {
-                    return Err(Error::new_static(
-                        ErrorCode::InvalidPlayer,
-                        "player is currently not at turn while bidding\0",
-                    ));
+                if Player::try_from(player).ok() != Some(state.source()) {
+                    return Err(SkatError::NotYourTurn);
                 }
                 if state.respond() {
-                    if mov.md > 1 {
-                        return Err(Error::new_static(
-                            ErrorCode::InvalidMove,
-                            "invalid bidding response\0",
-                        ));
+                    if md > 1 {
+                        return Err(SkatError::InvalidBiddingResponse);
                     }
-                } else if mov.md != 0
-                    && (mov.md <= self.bid.into() || mov.md > Self::MAXIMUM_BID.into())
+                } else if md != 0
+                    && (md <= self.bid.into()
+                        || md > Self::MAXIMUM_BID.into()
+                        || !Declaration::is_game_value(md as u16, &self.options.null_values))
+                {
+                    return Err(SkatError::InvalidBid);
+                } else if md != 0
+                    && self.options.canonical_bids
+                    && Some(md) != self.next_valid_bid_after(self.bid).map(move_code::from)
                 {
-                    return Err(Error::new_static(ErrorCode::InvalidMove, "invalid bid\0"));
+                    return Err(SkatError::NotNextBid);
                 }
             }
             GameState::SkatDecision => {
@@ -793,33 +2007,21 @@ impl GameMethods for Skat {
             }
             GameState::Picking => {
                 if player != PLAYER_RAND {
-                    return Err(Error::new_static(
-                        ErrorCode::InvalidPlayer,
-                        "PLAYER_RAND must pick up Skat cards\0",
-                    ));
+                    return Err(SkatError::PickerNotRandom);
                 }
                 let Some(skat_card) = self.cards.skat.last() else {
-                    return Err(Error::new_static(
-                        ErrorCode::InvalidState,
-                        "no card in the Skat to pick up\0",
-                    ));
+                    return Err(SkatError::NoSkatCard);
                 };
-                if let OptCard::Known(card) = mov.md.try_into()? {
+                if let OptCard::Known(card) = md.try_into()? {
                     match skat_card {
                         OptCard::Known(skat_card) => {
                             if card != *skat_card {
-                                return Err(Error::new_static(
-                                    ErrorCode::InvalidMove,
-                                    "not the correct card to pick up\0",
-                                ));
+                                return Err(SkatError::WrongSkatCard);
                             }
                         }
                         OptCard::Hidden => {
                             if self.cards.iter().any(|c| c == card) {
-                                return Err(Error::new_static(
-                                    ErrorCode::InvalidMove,
-                                    "this card is already at another place\0",
-                                ));
+                                return Err(SkatError::CardElsewhere);
                             }
                         }
                     }
@@ -828,109 +2030,231 @@ impl GameMethods for Skat {
             GameState::Putting => {
                 let hand = &self.cards[self.declarer];
                 if hand.is_empty() {
-                    return Err(Error::new_static(
-                        ErrorCode::InvalidState,
-                        "declarer's hand is empty\0",
-                    ));
+                    return Err(SkatError::EmptyHand);
                 }
 
-                if let OptCard::Known(card) = mov.md.try_into()? {
+                if let OptCard::Known(card) = md.try_into()? {
                     if !hand.iter_known().any(|c| c == card) {
                         if hand.iter().any(|c| matches!(c, OptCard::Hidden)) {
                             if self.cards.iter().any(|c| c == card) {
-                                return Err(Error::new_static(
-                                    ErrorCode::InvalidMove,
-                                    "this card is already at another place\0",
-                                ));
+                                return Err(SkatError::CardElsewhere);
                             }
                         } else {
-                            return Err(Error::new_static(
-                                ErrorCode::InvalidMove,
-                                "this card is not in the declarer's hand\0",
-                            ));
+                            return Err(SkatError::CardNotInHand);
                         }
                     }
                 }
             }
-            GameState::Declaring => 'b: {
-                let declaration: DeclarationMove = mov.md.try_into()?;
-                let Some(matadors) = self.calculate_matadors() else {break 'b;};
-
-                match declaration {
-                    DeclarationMove::Declare(declaration) => {
-                        if declaration.is_hand() != self.declaration.is_hand() {
-                            return Err(Error::new_static(
-                                ErrorCode::InvalidMove,
-                                if declaration.is_hand() {
-                                    "cannot declare Hand after picking up Skat\0"
-                                } else {
-                                    "you must declare a Hand game\0"
-                                },
-                            ));
-                        }
-                        if !declaration.allowed(self.bid, &matadors) {
-                            return Err(Error::new_static(
-                                ErrorCode::InvalidMove,
-                                "declaration would lead to overbidding\0",
-                            ));
-                        }
+            GameState::Declaring => match md.try_into()? {
+                DeclarationMove::Declare(declaration) => self.validate_declaration(declaration)?,
+                DeclarationMove::Overbidden => {
+                    if self.calculate_matadors().is_some()
+                        && Declaration::all(self.declaration.is_hand())
+                            .into_iter()
+                            .any(|d| self.validate_declaration(d).is_ok())
+                    {
+                        return Err(SkatError::NotOverbidden);
                     }
-                    DeclarationMove::Overbidden => {
-                        if Declaration::all(self.declaration.is_hand())
-                            .iter()
-                            .any(|d| d.allowed(self.bid, &matadors))
-                        {
-                            return Err(Error::new_static(
-                                ErrorCode::InvalidMove,
-                                "not actually overbidden\0",
-                            ));
-                        }
+                }
+                DeclarationMove::Spitze if !self.options.spitze => {
+                    return Err(SkatError::SpitzeDisabled);
+                }
+                DeclarationMove::Spitze if self.spitze => {
+                    return Err(SkatError::SpitzeAnnounced);
+                }
+                DeclarationMove::Spitze => {
+                    if !self.spitze_possible() {
+                        return Err(SkatError::SpitzeCardMissing);
                     }
                 }
-            }
+            },
             GameState::Revealing(i) => {
-                let card: Card = mov.md.try_into()?;
+                if player != player_id::from(self.declarer) {
+                    return Err(SkatError::NotDeclarer);
+                }
+                let card: Card = md.try_into()?;
                 let target = self.cards[self.declarer]
                     .get(i)
                     .ok_or_else(|| reveal_error(i))?;
                 match target {
                     OptCard::Hidden => {
                         if self.cards.iter().any(|c| c == card) {
-                            return Err(Error::new_static(
-                                ErrorCode::InvalidMove,
-                                "this card is already at another place\0",
-                            ));
+                            return Err(SkatError::CardElsewhere);
                         }
                     }
                     OptCard::Known(t) => {
                         if &card != t {
-                            return Err(Error::new_static(
-                                ErrorCode::InvalidMove,
-                                "not the correct card for this index\0",
-                            ));
+                            return Err(SkatError::WrongRevealCard);
                         }
                     }
                 }
             }
+            GameState::Playing(_) if matches!(md, Self::KONTRA | Self::RE) => {
+                if self.announcement() != Some(md) {
+                    return Err(SkatError::InvalidAnnouncement);
+                }
+            }
             GameState::Playing(ref state) => {
-                let card: Card = mov.md.try_into()?;
+                let card: Card = md.try_into()?;
                 if !self
                     .cards
                     .allowed(state.player, self.declaration)
                     .contains(&card)
                 {
-                    return Err(Error::new_static(
-                        ErrorCode::InvalidMove,
-                        "not allowed to play this card\0",
-                    ));
+                    return Err(SkatError::CardNotAllowed);
+                }
+            }
+            GameState::Finished(_) => return Err(SkatError::GameOver),
+        }
+
+        Ok(())
+    }
+}
+
+impl PartialEq for Skat {
+    fn eq(&self, other: &Self) -> bool {
+        todo!()
+    }
+}
+
+impl Eq for Skat {}
+
+impl Default for Skat {
+    fn default() -> Self {
+        Self {
+            cards: Default::default(),
+            bid: Self::MINIMUM_BID - 1,
+            // This will be overridden in the bidding phase anyway.
+            declarer: Player::Forehand,
+            declaration: Default::default(),
+            spitze: false,
+            pushes: 0,
+            picker: None,
+            forehand_seat: 0,
+            breakdown: None,
+            ramsch_result: None,
+            #[cfg(feature = "history")]
+            history: Default::default(),
+            state: Default::default(),
+            options: Default::default(),
+        }
+    }
+}
+
+impl GameMethods for Skat {
+    type Move = MoveCode;
+
+    fn create(init_info: &GameInit) -> Result<Self> {
+        #[allow(unused_mut)]
+        let mut game = match init_info {
+            GameInit::Default => Self::default(),
+            GameInit::Standard {
+                opts,
+                legacy: _,
+                state,
+            } => {
+                let mut game = Self {
+                    options: opts.map(str::parse).transpose()?.unwrap_or_default(),
+                    ..Default::default()
+                };
+                if let Some(state) = state {
+                    game.read_state(state)?;
                 }
+                game
             }
-            GameState::Finished(_) => todo!(),
+            GameInit::Serialized(_) => todo!(),
+        };
+        #[cfg(feature = "history")]
+        game.capture_history();
+        Ok(game)
+    }
+
+    fn export_options(
+        &mut self,
+        _player: player_id,
+        str_buf: &mut mirabel::ValidCString,
+    ) -> Result<()> {
+        write!(str_buf, "{}", self.options).expect("failed to write options");
+        Ok(())
+    }
+
+    fn copy_from(&mut self, other: &mut Self) -> Result<()> {
+        // FIXME: Reuse allocation or avoid dynamic allocations.
+        *self = other.clone();
+        Ok(())
+    }
+
+    fn player_count(&mut self) -> Result<u8> {
+        Ok(Player::COUNT.try_into().unwrap())
+    }
+
+    fn import_state(&mut self, string: Option<&str>) -> Result<()> {
+        match string {
+            Some(string) => self.read_state(string)?,
+            None => self.reset(),
         }
+        #[cfg(feature = "history")]
+        self.capture_history();
+        Ok(())
+    }
+
+    fn export_state(
+        &mut self,
+        player: player_id,
+        str_buf: &mut mirabel::ValidCString,
+    ) -> Result<()> {
+        self.write_state(player, str_buf)
+    }
 
+    fn players_to_move(&mut self, players: &mut Vec<player_id>) -> Result<()> {
+        players.extend(self.state.current_player(self.declarer));
         Ok(())
     }
 
+    fn get_concrete_moves(&mut self, player: player_id, moves: &mut Vec<Self::Move>) -> Result<()> {
+        self.concrete_moves(moves)
+    }
+
+    /// Convert a move string to a [`MoveCode`].
+    ///
+    /// Examples for dealing cards: `10S` for _10 of spades_ or `?` for a hidden
+    /// action.
+    fn get_move_data(&mut self, _player: player_id, string: &str) -> Result<Self::Move> {
+        Ok(self.parse_move(string)?.into())
+    }
+
+    fn get_move_str(
+        &mut self,
+        player: player_id,
+        mov: MoveDataSync<<Self::Move as MoveData>::Rust<'_>>,
+        str_buf: &mut mirabel::ValidCString,
+    ) -> Result<()> {
+        self.write_move(mov.md, str_buf)
+    }
+
+    fn make_move(
+        &mut self,
+        player: player_id,
+        mov: MoveDataSync<<Self::Move as MoveData>::Rust<'_>>,
+    ) -> Result<()> {
+        Ok(self.play(player, mov.md)?)
+    }
+
+    fn get_results(&mut self, players: &mut Vec<player_id>) -> Result<()> {
+        if let GameState::Finished(winners) = &self.state {
+            players.extend(winners.iter().map(|&p| player_id::from(p)));
+        }
+        Ok(())
+    }
+
+    fn is_legal_move(
+        &mut self,
+        player: player_id,
+        mov: MoveDataSync<<Self::Move as MoveData>::Rust<'_>>,
+    ) -> Result<()> {
+        Ok(self.check_move(player, mov.md)?)
+    }
+
     fn get_concrete_move_probabilities(
         &mut self,
         move_probabilities: &mut Vec<std::ffi::c_float>,
@@ -956,15 +2280,20 @@ impl GameMethods for Skat {
     ) -> Result<Self::Move> {
         // Catch misuse of this function and behave as the identity in this
         // case.
-        if player == target_player || target_player == PLAYER_RAND {
+        if player == target_player
+            || target_player == PLAYER_RAND
+            || self.options.perfect_information
+        {
             return Ok(mov.md.into());
         }
 
-        let target_player = Player::from(target_player);
+        let target_player = Player::try_from(target_player)?;
         match self.state {
             GameState::Dealing => {
-                assert_eq!(PLAYER_RAND, player);
-                let target = deal_to(self.cards.count());
+                if player != PLAYER_RAND {
+                    return Err(SkatError::DealerNotRandom.into());
+                }
+                let target = deal_to(self.cards.count())?;
                 if target.filter(|&t| t == target_player).is_some() {
                     Ok(mov.md.into())
                 } else {
@@ -972,7 +2301,9 @@ impl GameMethods for Skat {
                 }
             }
             GameState::Picking => {
-                assert_eq!(PLAYER_RAND, player);
+                if player != PLAYER_RAND {
+                    return Err(SkatError::PickerNotRandom.into());
+                }
                 if self.declarer == target_player {
                     Ok(mov.md.into())
                 } else {
@@ -980,6 +2311,7 @@ impl GameMethods for Skat {
                 }
             }
             GameState::Putting => Ok(OptCard::Hidden.into()),
+            // Revealed cards are public to everyone.
             _ => Ok(mov.md.into()),
         }
     }
@@ -992,37 +2324,127 @@ impl GameMethods for Skat {
     }
 
     fn redact_keep_state(&mut self, players: &[player_id]) -> Result<()> {
+        if self.options.perfect_information {
+            return Ok(());
+        }
         let mut keep = [false; Player::COUNT];
         for &player in players {
-            keep[Player::from(player) as usize] = true;
+            keep[Player::try_from(player)? as usize] = true;
         }
         self.cards.redact(keep);
         Ok(())
     }
 
-    fn print(&mut self, _player: player_id, str_buf: &mut mirabel::ValidCString) -> Result<()> {
-        write!(str_buf, "{}", self).expect("failed to write to print buffer");
+    #[cfg_attr(not(feature = "serde"), allow(unused_variables))]
+    fn print(&mut self, player: player_id, str_buf: &mut mirabel::ValidCString) -> Result<()> {
+        #[cfg(feature = "serde")]
+        if self.options.json_print {
+            write!(str_buf, "{}", self.to_json(player)).expect("failed to write to print buffer");
+            return Ok(());
+        }
+        if self.options.unicode_display {
+            write!(str_buf, "{self:#}")
+        } else {
+            write!(str_buf, "{self}")
+        }
+        .expect("failed to write to print buffer");
         Ok(())
     }
 }
 
 impl Display for Skat {
+    /// The alternate form uses suit symbols.
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        writeln!(f, "{}", self.describe_game_type())?;
+        let declaration = self.declaration();
         let mut cards = self.cards.clone();
-        cards.sort(self.declaration().filter(|d| d.is_null()).is_some());
-        writeln!(f, "{}", cards)?;
-        if self.bid >= Self::MINIMUM_BID {
-            writeln!(f, "highest bid: {}", self.bid)?;
+        cards.hands = Player::all().map(|p| self.cards.hand_for_display(p, declaration));
+        // Sort like a Grand game as long as nothing has been declared.
+        cards.skat.sort_for_declaration(
+            declaration.unwrap_or(Declaration::Normal(NormalMode::Grand, GameLevel::Normal)),
+        );
+        cards.fmt(f)?;
+        writeln!(f)?;
+        let hidden = self.cards.hidden_card_count();
+        if hidden > 0 {
+            writeln!(f, "{hidden} cards unknown to you")?;
         }
-        if self.state.has_declarer() {
-            writeln!(f, "{} is declarer", self.declarer)?;
+        if let Some(card) = self
+            .declaration()
+            .and_then(|d| self.cards.winning_card_in_trick(d))
+        {
+            self.fmt_card(card, f)?;
+            writeln!(f, " is winning the trick")?;
         }
-        if self.state.has_declaration() {
-            writeln!(f, "playing {}", self.declaration)?;
-        } else if self.declaration.is_hand() {
+        if let Some((trick, winner)) = self.declaration().and_then(|d| {
+            self.cards
+                .last_trick_cards(d)
+                .zip(self.cards.last_trick_winner(d))
+        }) {
+            write!(f, "last trick")?;
+            for card in trick {
+                write!(f, " ")?;
+                self.fmt_card(card, f)?;
+            }
+            writeln!(f, " won by {winner}")?;
+        }
+        if self.current_bid() >= Self::MINIMUM_BID {
+            writeln!(f, "highest bid: {}", self.current_bid())?;
+        }
+        if let Some(state) = self.bidding_state() {
+            let bidders = state.active_bidder_count(self.options.bidding_order);
+            if bidders > 1 {
+                writeln!(f, "{bidders} players still bidding")?;
+            }
+            if !state.respond() {
+                if let Some(bid) = self.next_valid_bid_after(self.current_bid()) {
+                    writeln!(f, "minimum bid: {bid}")?;
+                }
+            }
+        }
+        if self.state.has_declarer() && !matches!(self.declaration, Declaration::Ramsch) {
+            writeln!(
+                f,
+                "{} is declarer",
+                self.declarer.display_seat(self.forehand_seat)
+            )?;
+        }
+        if !self.state.has_declaration() && self.declaration.is_hand() {
             writeln!(f, "going to be a Hand game")?;
         }
-        writeln!(f, "{}", self.state)
+        if self.spitze {
+            writeln!(f, "Spitze announced")?;
+        }
+        if matches!(self.state, GameState::Playing(_)) {
+            writeln!(f, "goal: {}", self.describe_winning_condition())?;
+        }
+        if let Some(points) = self.declarer_points_needed() {
+            writeln!(f, "declarer needs {points} more points")?;
+        } else if let Some(points) = self.declarer_points_needed_against_schneider() {
+            writeln!(f, "declarer needs {points} more points to avoid Schneider")?;
+        }
+        if let Some(status) = self
+            .schneider_status()
+            .filter(|&s| s != SchneiderStatus::Open)
+        {
+            writeln!(f, "{status}")?;
+        }
+        if let Some(breakdown) = &self.breakdown {
+            writeln!(f, "{breakdown}")?;
+        }
+        if let Some(result) = &self.ramsch_result {
+            writeln!(f, "{result}")?;
+        }
+        if self.options.verbose_print && matches!(self.state, GameState::Finished(_)) {
+            self.fmt_trick_history(f)?;
+        }
+        match &self.state {
+            // Nobody escaped losing points in Ramsch.
+            GameState::Finished(winners) if winners.is_empty() && self.ramsch_result.is_some() => {
+                writeln!(f, "everybody lost")
+            }
+            state => writeln!(f, "{state}"),
+        }
     }
 }
 
@@ -1030,25 +2452,74 @@ impl Display for Skat {
 ///
 /// `dealt` is the number of already dealt cards.
 /// The returned value is either a [`Player`] or [`None`] for the Skat.
-///
-/// # Panics
-/// Panics if `dealt` is out of range.
-fn deal_to(dealt: u8) -> Option<Player> {
+/// Fails if all cards have already been dealt.
+pub(crate) fn deal_to(dealt: u8) -> SkatResult<Option<Player>> {
+    if usize::from(dealt) >= Card::COUNT {
+        return Err(SkatError::OverDealt);
+    }
+    Ok(deal_target(dealt))
+}
+
+/// Like [`deal_to()`] but panics if all cards have already been dealt.
+const fn deal_target(dealt: u8) -> Option<Player> {
     match dealt {
         0..=2 | 11..=14 | 23..=25 => Some(Player::Forehand),
         3..=5 | 15..=18 | 26..=28 => Some(Player::Middlehand),
         6..=8 | 19..=22 | 29..=31 => Some(Player::Rearhand),
         9..=10 => None,
-        32.. => panic!("dealt too many cards"),
+        32.. => panic!("all cards have already been dealt"),
     }
 }
 
+// Every player must be dealt a full hand and the rest goes into the Skat.
+const _: () = {
+    let mut counts = [0; Player::COUNT + 1];
+    let mut dealt = 0;
+    while dealt < Card::COUNT {
+        let target = match deal_target(dealt as u8) {
+            Some(player) => player as usize,
+            None => Player::COUNT,
+        };
+        counts[target] += 1;
+        dealt += 1;
+    }
+
+    let mut i = 0;
+    while i < Player::COUNT {
+        assert!(counts[i] == CardStruct::HAND_SIZE, "hand dealt wrongly");
+        i += 1;
+    }
+    assert!(
+        counts[Player::COUNT] == CardStruct::SKAT_SIZE,
+        "Skat dealt wrongly"
+    );
+};
+
+/// Returns the short name of `player` used in the replay format.
+///
+/// Ids which do not belong to a seat are written as [`PLAYER_RAND`].
+#[cfg(feature = "history")]
+fn replay_name(player: player_id) -> &'static str {
+    match Player::try_from(player) {
+        Ok(Player::Forehand) => "fore",
+        Ok(Player::Middlehand) => "mid",
+        Ok(Player::Rearhand) => "rear",
+        Err(_) => "rand",
+    }
+}
+
+/// Returns the binomial coefficient `n` choose `k`.
+fn binomial(n: usize, k: usize) -> u128 {
+    if k > n {
+        return 0;
+    }
+    let k = k.min(n - k);
+    (0..k).fold(1, |c, i| c * (n - i) as u128 / (i + 1) as u128)
+}
+
 /// Returns an error that the card i cannot be revealed as it does not exist.
-fn reveal_error(i: usize) -> Error {
-    Error::new_dynamic(
-        ErrorCode::InvalidState,
-        format!("cannot reveal card {i} as it does not exist"),
-    )
+fn reveal_error(i: usize) -> SkatError {
+    SkatError::RevealMissing(i)
 }
 
 fn generate_metadata() -> Metadata {
@@ -1062,6 +2533,7 @@ fn generate_metadata() -> Metadata {
             patch: 0,
         },
         features: GameFeatures {
+            options: true,
             random_moves: true,
             hidden_information: true,
             print: true,
@@ -1071,3 +2543,719 @@ fn generate_metadata() -> Metadata {
 }
 
 plugin_get_game_methods!(Skat{generate_metadata()});
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// Parses the whitespace-separated `cards`.
+    fn cards<const N: usize>(cards: &str) -> [Card; N] {
+        let cards: Vec<Card> = cards
+            .split_whitespace()
+            .map(|c| c.parse().unwrap())
+            .collect();
+        cards.try_into().unwrap()
+    }
+
+    /// Creates a Grand game in which only the last trick of `9C`, `8C`, and
+    /// `7C` is left and the declaring forehand already took
+    /// `declarer_points`.
+    fn last_trick(rear: &str, skat: &str, level: GameLevel, declarer_points: u8) -> Skat {
+        let hands = [
+            cards("9C JC JS AC 10C KC QC AS 10S KS"),
+            cards("8C JH 7S 8S 9S QS AH KH 9H 8H"),
+            cards(rear),
+        ];
+        let mut game = Skat::from_hands_skat_bid_declaration(
+            hands,
+            cards(skat),
+            18,
+            Player::Forehand,
+            Declaration::Normal(NormalMode::Grand, level),
+        )
+        .unwrap();
+        for (player, hand) in Player::all().into_iter().zip(hands) {
+            game.cards[player] = (&hand[..1]).into();
+            game.cards.played[player as usize] = hand[1..].to_vec();
+        }
+        let captured: u8 = game.cards.played.iter().flatten().copied().sum();
+        let GameState::Playing(state) = &mut game.state else {
+            unreachable!()
+        };
+        state.declarer_points = Some(declarer_points);
+        state.team_points = Some(captured - declarer_points);
+        game
+    }
+
+    /// Returns the usual [`last_trick()`] of a Normal Grand.
+    ///
+    /// The rearhand follows with the seven of clubs and the Skat holds the
+    /// ten and queen of hearts.
+    /// The declarer took 58 points in tricks, so the 13 points of the Skat
+    /// make them win.
+    fn grand_last_trick() -> Skat {
+        last_trick(
+            "7C AD 10D KD QD 9D 8D 7D 7H JD",
+            "10H QH",
+            GameLevel::Normal,
+            58,
+        )
+    }
+
+    /// Plays the last trick of [`last_trick()`].
+    fn play_last_trick(game: &mut Skat) {
+        for (player, card) in Player::all().into_iter().zip(["9C", "8C", "7C"]) {
+            game.play_str(player.into(), card).unwrap();
+        }
+    }
+
+    #[test]
+    fn from_hands_validation() {
+        let hands = [
+            cards("9C JC JS AC 10C KC QC AS 10S KS"),
+            cards("8C JH 7S 8S 9S QS AH KH 9H 8H"),
+            cards("7C AD 10D KD QD 9D 8D 7D 7H JD"),
+        ];
+        let grand = Declaration::Normal(NormalMode::Grand, GameLevel::Normal);
+        let game = Skat::from_hands_skat_bid_declaration(
+            hands,
+            cards("10H QH"),
+            18,
+            Player::Forehand,
+            grand,
+        )
+        .unwrap();
+        assert!(matches!(game.state, GameState::Playing(_)));
+        assert_eq!(game.cards[Player::Rearhand].len(), CardStruct::HAND_SIZE);
+
+        let create = |skat: &str, bid, declaration| {
+            Skat::from_hands_skat_bid_declaration(
+                hands,
+                cards(skat),
+                bid,
+                Player::Forehand,
+                declaration,
+            )
+        };
+        // The queen of hearts is missing.
+        assert!(create("10H 10H", 18, grand).is_err());
+        assert!(create("10H QH", 19, grand).is_err());
+        // Grand with two matadors is worth at most 120 even with Schwarz.
+        assert!(create("10H QH", 120, grand).is_ok());
+        assert!(create("10H QH", 144, grand).is_err());
+    }
+
+    #[test]
+    fn skat_counts_for_declarer() {
+        let mut game = grand_last_trick();
+        play_last_trick(&mut game);
+        assert_eq!(game.state, GameState::Finished(vec![Player::Forehand]));
+    }
+
+    #[test]
+    fn skat_counts_for_declarer_in_hand_game() {
+        let mut game = last_trick(
+            "7C AD 10D KD QD 9D 8D 7D 7H 10H",
+            "JD QH",
+            GameLevel::Hand,
+            60,
+        );
+        play_last_trick(&mut game);
+        assert_eq!(game.state, GameState::Finished(vec![Player::Forehand]));
+    }
+
+    #[test]
+    fn unannounced_schneider() {
+        for (only_if_announced, value) in [(false, 96), (true, 72)] {
+            // The defenders only took 17 points.
+            let mut game = last_trick(
+                "7C AD 10D KD QD 9D 8D 7D 7H JD",
+                "10H QH",
+                GameLevel::Normal,
+                90,
+            );
+            game.options.extras_count_only_if_announced = only_if_announced;
+            play_last_trick(&mut game);
+            let breakdown = game.breakdown.unwrap();
+            assert!(breakdown.won);
+            assert_eq!(breakdown.schneider, !only_if_announced);
+            assert_eq!(breakdown.value(), value);
+        }
+    }
+
+    #[test]
+    fn scores_if_game_ended_now() {
+        assert!(Skat::default()
+            .compute_all_scores_if_game_ended_now()
+            .is_err());
+        let game = grand_last_trick();
+        // Grand with two matadors while the Skat secures the win.
+        assert_eq!(
+            game.compute_all_scores_if_game_ended_now().unwrap(),
+            [72, 0, 0]
+        );
+    }
+
+    #[test]
+    fn pushes_double_ramsch_scores() {
+        let ramsch = RamschState {
+            trick_points: [30, 60, 30],
+            tricks_won: [3, 4, 3],
+        };
+        assert_eq!(ramsch.scores(0), [0, -60, 0]);
+        assert_eq!(ramsch.scores(2), [0, -240, 0]);
+        let durchmarsch = RamschState {
+            trick_points: [120, 0, 0],
+            tricks_won: [10, 0, 0],
+        };
+        assert_eq!(durchmarsch.scores(2), [480, 0, 0]);
+
+        // Everybody pushes the Skat unseen.
+        let mut game = Skat::default();
+        game.options.ramsch_on_all_pass = true;
+        game.options.ramsch_schieben = true;
+        play_first_moves(&mut game, |_| false);
+        assert_eq!(game.pushes, 3);
+        let result = game.ramsch_result.unwrap();
+        assert_eq!(result.scores, result.tallies.scores(0).map(|s| s * 8));
+        assert!(result.scores.iter().any(|&s| s != 0));
+    }
+
+    #[test]
+    fn putting_with_extra_card() {
+        let mut game = declaring(
+            "JC JS JH AC 10C KC QC AS 10S KS",
+            "9C 8C 7C QS 9S 8S 7S AH KH 9H",
+        );
+        // Forehand picked up the queen of hearts and holds an extra card.
+        let queen: OptCard = "QH".parse().unwrap();
+        let extra: OptCard = "9C".parse().unwrap();
+        game.cards.skat.retain(|&c| c != queen);
+        game.cards.give(Some(Player::Forehand), queen);
+        game.cards.take(Player::Middlehand, extra).unwrap();
+        game.cards.give(Some(Player::Forehand), extra);
+        game.state = GameState::Putting;
+
+        let cards = game.cards.clone();
+        assert_eq!(
+            game.apply_move(Player::Forehand.into(), queen.into()),
+            Err(SkatError::HandSizeMismatch)
+        );
+        assert_eq!(game.cards, cards);
+        assert_eq!(game.state, GameState::Putting);
+    }
+
+    #[test]
+    fn ramsch_winners() {
+        let tie = RamschState {
+            trick_points: [40, 40, 40],
+            tricks_won: [4, 3, 3],
+        };
+        assert!(tie.winners().is_empty());
+        assert_eq!(tie.scores(0), [-40, -40, -40]);
+        let shared = RamschState {
+            trick_points: [50, 50, 20],
+            tricks_won: [4, 4, 2],
+        };
+        assert_eq!(shared.winners(), [Player::Rearhand]);
+        let durchmarsch = RamschState {
+            trick_points: [120, 0, 0],
+            tricks_won: [10, 0, 0],
+        };
+        assert_eq!(durchmarsch.winners(), [Player::Forehand]);
+    }
+
+    /// Plays the first legal move until `stop` returns `true` or the game is
+    /// over.
+    fn play_first_moves(game: &mut Skat, stop: impl Fn(&Skat) -> bool) {
+        while !stop(game) {
+            let Some(player) = game.state.current_player(game.declarer) else {
+                return;
+            };
+            let mut moves: Vec<move_code> = vec![];
+            game.concrete_moves(&mut moves).unwrap();
+            game.play(player, moves[0]).unwrap();
+        }
+    }
+
+    #[test]
+    fn ramsch_result_kept() {
+        let mut game = Skat::default();
+        game.options.ramsch_on_all_pass = true;
+        play_first_moves(&mut game, |_| false);
+        let result = game.ramsch_result.clone().unwrap();
+        assert_eq!(result.tallies.trick_points.iter().sum::<u8>(), 120);
+        assert_eq!(game.state, GameState::Finished(result.tallies.winners()));
+        assert!(game.to_string().contains(&result.to_string()));
+
+        let exported = game.to_export_string(mirabel::game::PLAYER_NONE);
+        let imported = Skat::from_export_string(&exported).unwrap();
+        assert_eq!(imported.ramsch_result, Some(result));
+    }
+
+    #[test]
+    fn ramsch_skat_hidden() {
+        let mut game = Skat::default();
+        game.options.ramsch_on_all_pass = true;
+        // The Skat holds KD and AD.
+        for card in &null_hand_moves()[..Card::COUNT] {
+            game.play_str(PLAYER_RAND, card).unwrap();
+        }
+        play_first_moves(&mut game, |g| g.cards.hands.iter().all(|h| h.len() <= 1));
+        let mut redacted = game.clone();
+        for card in redacted.cards.skat.iter_mut() {
+            *card = OptCard::Hidden;
+        }
+        play_first_moves(&mut game, |_| false);
+        play_first_moves(&mut redacted, |_| false);
+        assert!(game.ramsch_result.is_some());
+        assert_eq!(redacted.ramsch_result, game.ramsch_result);
+        assert_eq!(redacted.state, game.state);
+    }
+
+    #[test]
+    fn verify_declared_game_value() {
+        assert!(Skat::default().verify_game_value().is_err());
+        let mut game = grand_last_trick();
+        let breakdown = game.verify_game_value().unwrap();
+        assert_eq!(breakdown.value(), 72);
+        assert!(!breakdown.overbid());
+        game.bid = 96;
+        assert!(game.verify_game_value().unwrap().overbid());
+        game.cards.hands[0][0] = OptCard::Hidden;
+        assert!(game.verify_game_value().is_err());
+    }
+
+    #[test]
+    fn no_moves_after_game_over() {
+        let mut game = grand_last_trick();
+        play_last_trick(&mut game);
+        let mut moves: Vec<move_code> = vec![];
+        game.concrete_moves(&mut moves).unwrap();
+        assert!(moves.is_empty());
+        assert!(game.parse_move("7S").is_err());
+        assert!(game.check_move(Player::Forehand.into(), 0).is_err());
+        assert!(game.apply_move(Player::Forehand.into(), 0).is_err());
+        assert!(game.write_move(0, &mut String::new()).is_err());
+    }
+
+    #[test]
+    fn explain_moves() {
+        let mut game = grand_last_trick();
+        let card: Card = "9C".parse().unwrap();
+        assert_eq!(
+            game.explain_move(card.into()).unwrap(),
+            "Play the 9 of clubs (0 points)"
+        );
+        play_last_trick(&mut game);
+        assert!(game.explain_move(card.into()).is_err());
+    }
+
+    /// Returns a game in which forehand declares after picking up the Skat
+    /// with _Spitze_ enabled.
+    fn declaring(fore: &str, middle: &str) -> Skat {
+        let mut game = Skat::from_hands_skat_bid_declaration(
+            [
+                cards(fore),
+                cards(middle),
+                cards("8H 7H AD 10D KD QD 9D 8D 7D JD"),
+            ],
+            cards("10H QH"),
+            18,
+            Player::Forehand,
+            Declaration::default(),
+        )
+        .unwrap();
+        game.options.spitze = true;
+        game.state = GameState::Declaring;
+        game
+    }
+
+    #[test]
+    fn metrics_of_hand() {
+        let mut game = declaring(
+            "JC JS JH AC 10C KC QC AS 10S KS",
+            "9C 8C 7C QS 9S 8S 7S AH KH 9H",
+        );
+        let metrics = game.hand_metrics(Player::Forehand).unwrap();
+        assert_eq!(metrics.jacks, 3);
+        assert_eq!(metrics.aces, 2);
+        assert_eq!(metrics.tens, 2);
+        assert_eq!(metrics.trump_length, [7, 6, 3, 3]);
+        // Three jacks from the top, two aces, and two tens backed by them.
+        assert_eq!(metrics.sure_tricks, 7);
+        game.cards.hands[0][0] = OptCard::Hidden;
+        assert!(game.hand_metrics(Player::Forehand).is_err());
+    }
+
+    #[test]
+    fn bid_strength() {
+        let mut game = declaring(
+            "JC JS JH AC 10C KC QC AS 10S KS",
+            "9C 8C 7C QS 9S 8S 7S AH KH 9H",
+        );
+        // Grand with three matadors.
+        assert_eq!(game.estimate_bid_strength(Player::Forehand).unwrap(), 96);
+        // Middlehand does not hold half of the trumps of any game.
+        assert_eq!(game.estimate_bid_strength(Player::Middlehand).unwrap(), 0);
+        game.cards.hands[0][0] = OptCard::Hidden;
+        assert!(game.estimate_bid_strength(Player::Forehand).is_err());
+    }
+
+    #[test]
+    fn spitze_requires_lowest_trump() {
+        let spitze = move_code::from(DeclarationMove::Spitze);
+        let game = declaring(
+            "JC JS JH AC 10C KC QC AS 10S KS",
+            "9C 8C 7C QS 9S 8S 7S AH KH 9H",
+        );
+        let mut moves: Vec<move_code> = vec![];
+        game.concrete_moves(&mut moves).unwrap();
+        assert!(!moves.contains(&spitze));
+        assert!(game.check_move(1, spitze).is_err());
+
+        let game = declaring(
+            "JC JS JH AC 10C KC 7C AS 10S KS",
+            "9C 8C QC QS 9S 8S 7S AH KH 9H",
+        );
+        let mut moves: Vec<move_code> = vec![];
+        game.concrete_moves(&mut moves).unwrap();
+        assert!(moves.contains(&spitze));
+        game.check_move(1, spitze).unwrap();
+    }
+
+    #[test]
+    fn re_after_forehand_declarer_played() {
+        let mut game = Skat::from_hands_skat_bid_declaration(
+            [
+                cards("9C JC JS AC 10C KC QC AS 10S KS"),
+                cards("8C JH 7S 8S 9S QS AH KH 9H 8H"),
+                cards("7C AD 10D KD QD 9D 8D 7D 7H JD"),
+            ],
+            cards("10H QH"),
+            18,
+            Player::Forehand,
+            Declaration::Normal(NormalMode::Grand, GameLevel::Normal),
+        )
+        .unwrap();
+        game.options.kontra_re = true;
+
+        game.play_str(1, "JC").unwrap();
+        game.play_str(2, "Kontra").unwrap();
+        game.play_str(2, "JH").unwrap();
+        game.play_str(3, "JD").unwrap();
+        assert!(game
+            .to_string()
+            .contains("last trick JC JH JD won by forehand"));
+        assert_eq!(game.legal_moves_str().unwrap().last().unwrap(), "Re");
+        game.play_str(1, "JS").unwrap();
+        game.play_str(2, "8C").unwrap();
+        game.play_str(3, "7C").unwrap();
+        assert!(!game.legal_moves_str().unwrap().contains(&"Re".to_string()));
+        assert!(game.play_str(1, "Re").is_err());
+    }
+
+    #[test]
+    fn schneider_requires_hand() {
+        let game = declaring(
+            "JC JS JH AC 10C KC 7C AS 10S KS",
+            "9C 8C QC QS 9S 8S 7S AH KH 9H",
+        );
+        for level in [GameLevel::Hand, GameLevel::Schneider, GameLevel::Schwarz] {
+            let declaration = Declaration::Normal(NormalMode::Grand, level);
+            assert!(game.check_move(1, declaration.into()).is_err());
+        }
+        let declaration = Declaration::Normal(NormalMode::Grand, GameLevel::Normal);
+        game.check_move(1, declaration.into()).unwrap();
+    }
+
+    #[test]
+    fn safe_declarations_reach_bid() {
+        let mut game = declaring(
+            "JC JS JH AC 10C KC QC AS 10S KS",
+            "9C 8C 7C QS 9S 8S 7S AH KH 9H",
+        );
+        let clubs = Declaration::Normal(
+            NormalMode::Color(structures::Suit::Clubs),
+            GameLevel::Normal,
+        );
+        let grand = Declaration::Normal(NormalMode::Grand, GameLevel::Normal);
+        assert!(game.safe_declarations().contains(&clubs));
+        // Clubs with three matadors is only worth 48.
+        game.bid = 50;
+        assert_eq!(game.minimum_game_value_required(), 50);
+        let safe = game.safe_declarations();
+        assert!(!safe.contains(&clubs));
+        assert!(safe.contains(&grand));
+    }
+
+    #[test]
+    fn count_possible_states() {
+        assert_eq!(binomial(32, 10), 64_512_240);
+        assert_eq!(binomial(2, 3), 0);
+        let mut game = grand_last_trick();
+        assert_eq!(game.possible_states_count(), 1);
+        // Either the 9 or the 8 of clubs could be held by forehand.
+        game.cards.hands[0][0] = OptCard::Hidden;
+        game.cards.hands[1][0] = OptCard::Hidden;
+        assert_eq!(game.possible_states_count(), 2);
+    }
+
+    #[test]
+    fn next_deal_rotates_forehand() {
+        let mut game = grand_last_trick();
+        game.options.kontra_re = true;
+        game.forehand_seat = 2;
+        let next = game.next_deal();
+        assert_eq!(next.forehand_seat, 0);
+        assert_eq!(next.state, GameState::Dealing);
+        assert_eq!(next.cards.iter().count(), 0);
+        assert!(next.options.kontra_re);
+        assert_eq!(next.next_deal().forehand_seat, 1);
+    }
+
+    #[test]
+    fn points_needed_against_schneider() {
+        let mut game = Skat::from_hands_skat_bid_declaration(
+            [
+                cards("9C JC JS AC 10C KC QC AS 10S KS"),
+                cards("8C JH 7S 8S 9S QS AH KH 9H 8H"),
+                cards("7C AD 10D KD QD 9D 8D 7D 7H JD"),
+            ],
+            cards("10H QH"),
+            18,
+            Player::Forehand,
+            Declaration::Normal(NormalMode::Grand, GameLevel::Normal),
+        )
+        .unwrap();
+        // The Skat already brings 13 points.
+        assert_eq!(game.declarer_points_needed(), Some(48));
+        assert_eq!(game.declarer_points_needed_against_schneider(), Some(18));
+        assert!(game.to_string().contains("declarer needs 48 more points\n"));
+        // Only escaping Schneider is left if winning is out of reach.
+        game.options.point_thresholds.winning = Card::POINTS_TOTAL + 1;
+        assert!(game
+            .to_string()
+            .contains("declarer needs 18 more points to avoid Schneider"));
+    }
+
+    #[test]
+    fn levels_require_hand() {
+        let game = declaring(
+            "JC JS JH AC 10C KC 7C AS 10S KS",
+            "9C 8C QC QS 9S 8S 7S AH KH 9H",
+        );
+        for level in [GameLevel::Schneider, GameLevel::Schwarz, GameLevel::Ouvert] {
+            let declaration = Declaration::Normal(NormalMode::Grand, level);
+            assert_eq!(
+                game.validate_declaration(declaration),
+                Err(SkatError::LevelRequiresHand)
+            );
+            assert!(game.check_move(1, declaration.into()).is_err());
+        }
+    }
+
+    #[test]
+    fn print_card_names() {
+        let mut game = grand_last_trick();
+        game.play_str(1, "9C").unwrap();
+        assert!(game.to_string().contains("9C is winning the trick"));
+        game.options.card_names = CardNames::English;
+        assert!(game.to_string().contains("9 of clubs is winning the trick"));
+        game.options.card_names = CardNames::German;
+        assert!(game.to_string().contains("Neun Kreuz is winning the trick"));
+    }
+
+    #[test]
+    fn perft_dealing() {
+        let game = Skat::default();
+        assert_eq!(game.perft(0).unwrap(), 1);
+        assert_eq!(game.perft(1).unwrap(), 32);
+        assert_eq!(game.perft(2).unwrap(), 32 * 31);
+        assert_eq!(game.perft(3).unwrap(), 32 * 31 * 30);
+    }
+
+    #[test]
+    fn perft_last_trick() {
+        let game = grand_last_trick();
+        assert_eq!(game.perft(3).unwrap(), 1);
+        assert_eq!(game.perft(4).unwrap(), 1);
+    }
+
+    #[test]
+    fn active_bidders() {
+        use BiddingOrder::{RearhandFirst, Standard};
+        use BiddingState::*;
+
+        assert_eq!(ForeRespondsMiddle.active_bidder_count(Standard), 3);
+        assert_eq!(RearCallsMiddle.active_bidder_count(Standard), 2);
+        assert_eq!(RearCallsMiddle.active_bidder_count(RearhandFirst), 3);
+        assert_eq!(RearCallsFore.active_bidder_count(RearhandFirst), 2);
+        assert_eq!(Forehand.active_bidder_count(Standard), 1);
+
+        let mut game = Skat::default();
+        for card in Card::all() {
+            game.play(PLAYER_RAND, card.into()).unwrap();
+        }
+        assert!(game.to_string().contains("3 players still bidding"));
+        game.play_str(2, "pass").unwrap();
+        assert!(game.to_string().contains("2 players still bidding"));
+        game.play_str(3, "pass").unwrap();
+        assert!(!game.to_string().contains("still bidding"));
+    }
+
+    #[test]
+    fn bidding_results() {
+        use BiddingOrder::{RearhandFirst, Standard};
+        use BiddingState::*;
+
+        let result = RearCallsMiddle.next(true, true, Standard);
+        assert!(result.is_finished());
+        assert_eq!(result.declarer(), Some(Player::Middlehand));
+        let result = RearCallsMiddle.next(true, true, RearhandFirst);
+        assert!(!result.is_finished() && !result.is_draw());
+        assert_eq!(result.declarer(), None);
+        let result = Forehand.next(true, false, Standard);
+        assert!(result.is_draw() && !result.is_finished());
+        assert!(Forehand.next(false, false, Standard).is_finished());
+    }
+
+    #[test]
+    fn legal_moves_as_strings() {
+        let mut game = Skat::default();
+        assert_eq!(game.legal_moves_str().unwrap().len(), Card::COUNT);
+        for card in Card::all() {
+            game.play(PLAYER_RAND, card.into()).unwrap();
+        }
+        let moves = game.legal_moves_str().unwrap();
+        assert_eq!(moves[..3], ["pass", "18", "20"]);
+
+        let mut game = grand_last_trick();
+        assert_eq!(game.legal_moves_str().unwrap(), ["9C"]);
+        play_last_trick(&mut game);
+        assert!(game.legal_moves_str().unwrap().is_empty());
+    }
+
+    #[test]
+    fn display_winners() {
+        let state = GameState::Finished(vec![Player::Middlehand, Player::Rearhand]);
+        assert_eq!(state.to_string(), "middlehand and rearhand won");
+        let state = GameState::Finished(vec![Player::Forehand]);
+        assert_eq!(state.to_string(), "forehand won");
+    }
+
+    #[test]
+    fn transcript_move_reason() {
+        assert!(Skat::validate_transcript(&GameInit::Default, &["7C", "8C"]).is_ok());
+        assert!(Skat::validate_transcript(&GameInit::Default, &["7C", "7C"]).is_err());
+        let mut game = Skat::default();
+        game.play_str(PLAYER_RAND, "7C").unwrap();
+        let reason = game.play_str(PLAYER_RAND, "7C").unwrap_err();
+        assert_eq!(reason, SkatError::CardAlreadyDealt);
+        let error = SkatError::TranscriptMove(1, Box::new(reason));
+        assert_eq!(
+            error.to_string(),
+            "move 1 of the transcript is invalid: this card has already been dealt"
+        );
+    }
+
+    /// Returns the moves of the game of `tests/null.expect` in which
+    /// forehand wins a _Null Hand_.
+    fn null_hand_moves() -> Vec<&'static str> {
+        let deal = "7C 8C 9C 10C JC QC 10H JH QH KD AD 7S 8S 9S 7H KC AC 10S JS KH AH 8D 9D 8H 9H 7D QS KS AS 10D JD QD";
+        let tricks = "7C 10C 10H 10S KH 7S JC AH 8C QC JH 9C KC QH 7H AC 8D 8H JS 9D 8S QS 10D 9S KS JD 9H AS QD 7D";
+        deal.split_whitespace()
+            .chain(["pass", "pass", "accept", "Hand", "Null Hand"])
+            .chain(tricks.split_whitespace())
+            .collect()
+    }
+
+    #[test]
+    #[cfg(feature = "history")]
+    fn notation_round_trip() {
+        let game = Skat::validate_transcript(&GameInit::Default, &null_hand_moves()).unwrap();
+        let notation = game.to_notation();
+        let lines: Vec<_> = notation.lines().collect();
+        assert_eq!(lines.len(), 6);
+        assert!(lines[0].starts_with("deal: 7C, 8C, "));
+        assert_eq!(lines[1], "bidding: pass, pass, 18");
+        assert_eq!(lines[5], "result: forehand won");
+
+        let game = Skat::from_notation(&GameInit::Default, &notation).unwrap();
+        assert_eq!(game.state, GameState::Finished(vec![Player::Forehand]));
+        assert_eq!(Skat::default().to_notation(), "result: dealing");
+    }
+
+    #[test]
+    fn notation_needs_phases() {
+        let game =
+            Skat::from_notation(&GameInit::Default, "deal: 7C, 8C\nresult: dealing").unwrap();
+        assert_eq!(game.cards.count(), 2);
+        assert!(Skat::from_notation(&GameInit::Default, "7C, 8C").is_err());
+    }
+
+    #[test]
+    #[cfg(feature = "history")]
+    fn replay_round_trip() {
+        let moves = null_hand_moves();
+        let game = Skat::validate_transcript(&GameInit::Default, &moves).unwrap();
+        let replay = game.export_to_replay_format();
+        let lines: Vec<_> = replay.lines().collect();
+        assert_eq!(lines.len(), moves.len());
+        assert_eq!(lines[0], "rand 7C");
+        assert_eq!(lines[Card::COUNT], "mid pass");
+        assert_eq!(lines.last(), Some(&"fore 7D"));
+
+        let game = Skat::from_replay_format(&GameInit::Default, &replay).unwrap();
+        assert_eq!(game.state, GameState::Finished(vec![Player::Forehand]));
+        assert!(Skat::default().export_to_replay_format().is_empty());
+    }
+
+    #[test]
+    fn replay_checks_players() {
+        let game = Skat::from_replay_format(&GameInit::Default, "rand 7C\nrand 8C").unwrap();
+        assert_eq!(game.cards.count(), 2);
+        assert!(Skat::from_replay_format(&GameInit::Default, "fore 7C").is_err());
+        assert!(Skat::from_replay_format(&GameInit::Default, "dealer 7C").is_err());
+    }
+
+    /// Plays the game of `tests/null.expect`.
+    #[test]
+    fn null_hand_winner() {
+        let mut game = Skat::default();
+        for card in "7C 8C 9C 10C JC QC 10H JH QH KD AD 7S 8S 9S 7H KC AC 10S JS KH AH 8D 9D 8H 9H 7D QS KS AS 10D JD QD".split_whitespace() {
+            game.play_str(PLAYER_RAND, card).unwrap();
+        }
+        game.play_str(2, "pass").unwrap();
+        game.play_str(3, "pass").unwrap();
+        game.play_str(1, "accept").unwrap();
+        game.play_str(1, "Hand").unwrap();
+        game.play_str(1, "Null Hand").unwrap();
+        for (player, card) in [(1, "7C"), (2, "10C"), (3, "10H")] {
+            game.play_str(player, card).unwrap();
+        }
+        // Middlehand wins every trick.
+        for trick in [
+            ["10S", "KH", "7S"],
+            ["JC", "AH", "8C"],
+            ["QC", "JH", "9C"],
+            ["KC", "QH", "7H"],
+            ["AC", "8D", "8H"],
+            ["JS", "9D", "8S"],
+            ["QS", "10D", "9S"],
+            ["KS", "JD", "9H"],
+            ["AS", "QD", "7D"],
+        ] {
+            for (player, card) in [2, 3, 1].into_iter().zip(trick) {
+                game.play_str(player, card).unwrap();
+            }
+        }
+        assert_eq!(game.state, GameState::Finished(vec![Player::Forehand]));
+        assert_eq!(game.state.to_string(), "forehand won");
+        game.options.verbose_print = true;
+        let printed = game.to_string();
+        assert!(printed.contains("trick 1: 7C 10C 10H → middlehand wins (20 points)"));
+        assert!(printed.contains("trick 10: AS QD 7D → middlehand wins (14 points)"));
+        assert!(printed.contains("points from tricks: forehand 0, middlehand 105, rearhand 0"));
+    }
+}
@@ -0,0 +1,365 @@
+use std::{
+    fmt::{self, Display},
+    str::FromStr,
+};
+
+use mirabel::error::{Error, Result};
+use nom::{
+    branch::alt,
+    bytes::complete::{tag_no_case, take_while1},
+    character::complete::{char, space0, u8 as parse_u8},
+    combinator::{eof, map, value, verify},
+    error::{context, convert_error},
+    multi::separated_list0,
+    sequence::{delimited, separated_pair, terminated},
+    Finish,
+};
+
+use crate::{
+    error::SkatError,
+    structures::{Card, IResult, NullValues},
+};
+
+/// Options for configuring the game.
+///
+/// These are parsed from a comma-separated list of `key=value` pairs like
+/// `canonical_bids=true`.
+/// Missing keys keep their default value.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub(crate) struct GameOptions {
+    /// Only offer the next valid bid value instead of all higher ones when
+    /// calling.
+    pub canonical_bids: bool,
+    /// Allow the declarer to announce _Spitze_, i.e., winning the last trick
+    /// with the lowest trump.
+    pub spitze: bool,
+    /// Automatically apply forced moves like picking up or revealing known
+    /// cards.
+    pub auto_resolve: bool,
+    /// The values of the Null games.
+    pub null_values: NullValues,
+    /// Reveal the Skat to all players when the game is over.
+    pub reveal_skat_after_game: bool,
+    /// Play a _Ramsch_ game instead of ending in a draw when everybody passed.
+    pub ramsch_on_all_pass: bool,
+    /// Let every player pick up or push the Skat before playing _Ramsch_
+    /// (_Schieben_).
+    pub ramsch_schieben: bool,
+    /// Allow the defenders to announce _Kontra_ and the declarer to answer
+    /// with _Re_.
+    pub kontra_re: bool,
+    /// The order in which the players meet while bidding.
+    pub bidding_order: BiddingOrder,
+    /// Only count _Schneider_ and _Schwarz_ for the game value if they were
+    /// announced.
+    pub extras_count_only_if_announced: bool,
+    /// Print the history of all tricks when the game is over.
+    pub verbose_print: bool,
+    /// The card points needed for winning and being _Schneider_.
+    pub point_thresholds: PointThresholds,
+    /// Double the score of every normal and _Null_ game (_Bock_).
+    pub bock: bool,
+    /// Print suits as symbols like `♣` instead of letters.
+    pub unicode_display: bool,
+    /// Never hide any cards, e.g., for local games on a shared screen.
+    pub perfect_information: bool,
+    /// The names of single cards when printing.
+    pub card_names: CardNames,
+    /// Print the state as JSON like [`crate::Skat::to_json()`] instead of
+    /// the human-readable form.
+    #[cfg(feature = "serde")]
+    pub json_print: bool,
+}
+
+/// Order of the pairings in the bidding phase.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub(crate) enum BiddingOrder {
+    /// Middlehand calls forehand first and rearhand then calls the survivor.
+    #[default]
+    Standard,
+    /// Rearhand calls middlehand first and the survivor then calls forehand.
+    RearhandFirst,
+}
+
+impl BiddingOrder {
+    fn parse(input: &str) -> IResult<&str, Self> {
+        context(
+            "bidding order",
+            alt((
+                value(Self::Standard, tag_no_case("standard")),
+                value(Self::RearhandFirst, tag_no_case("rearhand_first")),
+            )),
+        )(input)
+    }
+}
+
+impl Display for BiddingOrder {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Standard => write!(f, "standard"),
+            Self::RearhandFirst => write!(f, "rearhand_first"),
+        }
+    }
+}
+
+/// Names of the cards when printing the state.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub(crate) enum CardNames {
+    /// Short names as used for moves like `JC`.
+    #[default]
+    Short,
+    /// English names like `jack of clubs`.
+    English,
+    /// German names like `Bube Kreuz`.
+    German,
+}
+
+impl CardNames {
+    fn parse(input: &str) -> IResult<&str, Self> {
+        context(
+            "card names",
+            alt((
+                value(Self::Short, tag_no_case("short")),
+                value(Self::English, tag_no_case("english")),
+                value(Self::German, tag_no_case("german")),
+            )),
+        )(input)
+    }
+}
+
+impl Display for CardNames {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Short => write!(f, "short"),
+            Self::English => write!(f, "english"),
+            Self::German => write!(f, "german"),
+        }
+    }
+}
+
+/// Card points deciding the outcome of a normal game.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub(crate) struct PointThresholds {
+    /// The declarer wins with at least these many points.
+    pub winning: u8,
+    /// A party is _Schneider_ with these many or less points.
+    pub schneider: u8,
+}
+
+impl PointThresholds {
+    /// Parses two slash-separated values like `61/30`.
+    ///
+    /// The winning threshold must not exceed [`Card::POINTS_TOTAL`] and must
+    /// be above the _Schneider_ threshold.
+    fn parse(input: &str) -> IResult<&str, Self> {
+        context(
+            "point thresholds",
+            map(
+                verify(
+                    separated_pair(parse_u8, char('/'), parse_u8),
+                    |&(winning, schneider)| winning <= Card::POINTS_TOTAL && schneider < winning,
+                ),
+                |(winning, schneider)| Self { winning, schneider },
+            ),
+        )(input)
+    }
+}
+
+impl Default for PointThresholds {
+    fn default() -> Self {
+        Self {
+            winning: 61,
+            schneider: 30,
+        }
+    }
+}
+
+impl Display for PointThresholds {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}/{}", self.winning, self.schneider)
+    }
+}
+
+impl GameOptions {
+    /// Parses a list of `key=value` pairs without interpreting them.
+    fn parse(input: &str) -> IResult<&str, Vec<(&str, &str)>> {
+        context(
+            "options",
+            separated_list0(
+                delimited(space0, char(','), space0),
+                separated_pair(
+                    context(
+                        "key",
+                        take_while1(|c: char| c.is_ascii_alphanumeric() || c == '_'),
+                    ),
+                    delimited(space0, char('='), space0),
+                    context(
+                        "value",
+                        take_while1(|c: char| c != ',' && !c.is_whitespace()),
+                    ),
+                ),
+            ),
+        )(input)
+    }
+
+    /// Sets the option `key` to the unparsed `raw` value.
+    fn set(&mut self, key: &str, raw: &str) -> Result<()> {
+        match key {
+            "canonical_bids" => self.canonical_bids = parse_bool(key, raw)?,
+            "spitze" => self.spitze = parse_bool(key, raw)?,
+            "auto_resolve" => self.auto_resolve = parse_bool(key, raw)?,
+            "reveal_skat_after_game" => self.reveal_skat_after_game = parse_bool(key, raw)?,
+            "ramsch_on_all_pass" => self.ramsch_on_all_pass = parse_bool(key, raw)?,
+            "ramsch_schieben" => self.ramsch_schieben = parse_bool(key, raw)?,
+            "kontra_re" => self.kontra_re = parse_bool(key, raw)?,
+            "extras_count_only_if_announced" => {
+                self.extras_count_only_if_announced = parse_bool(key, raw)?
+            }
+            "verbose_print" => self.verbose_print = parse_bool(key, raw)?,
+            "bock" => self.bock = parse_bool(key, raw)?,
+            "unicode_display" => self.unicode_display = parse_bool(key, raw)?,
+            "perfect_information" => self.perfect_information = parse_bool(key, raw)?,
+            #[cfg(feature = "serde")]
+            "json_print" => self.json_print = parse_bool(key, raw)?,
+            "bidding_order" => {
+                self.bidding_order = terminated(BiddingOrder::parse, eof)(raw)
+                    .finish()
+                    .map_err(|e| SkatError::ParseOptions(convert_error(raw, e)))?
+                    .1
+            }
+            "card_names" => {
+                self.card_names = terminated(CardNames::parse, eof)(raw)
+                    .finish()
+                    .map_err(|e| SkatError::ParseOptions(convert_error(raw, e)))?
+                    .1
+            }
+            "point_thresholds" => {
+                self.point_thresholds = terminated(PointThresholds::parse, eof)(raw)
+                    .finish()
+                    .map_err(|e| SkatError::ParseOptions(convert_error(raw, e)))?
+                    .1
+            }
+            "null_values" => {
+                self.null_values = terminated(NullValues::parse, eof)(raw)
+                    .finish()
+                    .map_err(|e| SkatError::ParseOptions(convert_error(raw, e)))?
+                    .1
+            }
+            _ => return Err(SkatError::UnknownOption(key.to_string()).into()),
+        }
+        Ok(())
+    }
+}
+
+impl FromStr for GameOptions {
+    type Err = Error;
+
+    /// Parses the options like [`Self::parse()`] but with trimming.
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        let pairs = terminated(delimited(space0, GameOptions::parse, space0), eof)(s)
+            .finish()
+            .map_err(|e| SkatError::ParseOptions(convert_error(s, e)))?
+            .1;
+
+        let mut options = Self::default();
+        for (key, raw) in pairs {
+            options.set(key, raw)?;
+        }
+        Ok(options)
+    }
+}
+
+impl Display for GameOptions {
+    /// Writes all options in a format understood by [`Self::from_str()`].
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "canonical_bids={},spitze={},auto_resolve={},null_values={},\
+             reveal_skat_after_game={},ramsch_on_all_pass={},\
+             ramsch_schieben={},kontra_re={},bidding_order={},\
+             extras_count_only_if_announced={},verbose_print={},\
+             point_thresholds={},bock={},unicode_display={},\
+             perfect_information={},card_names={}",
+            self.canonical_bids,
+            self.spitze,
+            self.auto_resolve,
+            self.null_values,
+            self.reveal_skat_after_game,
+            self.ramsch_on_all_pass,
+            self.ramsch_schieben,
+            self.kontra_re,
+            self.bidding_order,
+            self.extras_count_only_if_announced,
+            self.verbose_print,
+            self.point_thresholds,
+            self.bock,
+            self.unicode_display,
+            self.perfect_information,
+            self.card_names
+        )?;
+        #[cfg(feature = "serde")]
+        write!(f, ",json_print={}", self.json_print)?;
+        Ok(())
+    }
+}
+
+/// Parses `raw` as a boolean for the option `key`.
+fn parse_bool(key: &str, raw: &str) -> Result<bool> {
+    terminated(
+        alt((
+            value(true, tag_no_case("true")),
+            value(false, tag_no_case("false")),
+        )),
+        eof,
+    )(raw)
+    .finish()
+    .map(|(_, b)| b)
+    .map_err(|_: nom::error::VerboseError<&str>| {
+        SkatError::InvalidBoolOption(key.to_string()).into()
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn card_names() {
+        let options: GameOptions = "card_names=German".parse().unwrap();
+        assert_eq!(options.card_names, CardNames::German);
+        assert_eq!(options.to_string().parse::<GameOptions>().unwrap(), options);
+        assert!("card_names=french".parse::<GameOptions>().is_err());
+    }
+
+    #[test]
+    fn default_point_thresholds() {
+        let thresholds = PointThresholds::default();
+        assert_eq!((thresholds.winning, thresholds.schneider), (61, 30));
+        let options: GameOptions = "point_thresholds=61/30".parse().unwrap();
+        assert_eq!(options, GameOptions::default());
+    }
+
+    #[test]
+    fn custom_point_thresholds() {
+        let options: GameOptions = "point_thresholds=120/0".parse().unwrap();
+        assert_eq!(
+            options.point_thresholds,
+            PointThresholds {
+                winning: 120,
+                schneider: 0
+            }
+        );
+    }
+
+    #[test]
+    fn invalid_point_thresholds() {
+        for raw in ["121/30", "255/30", "61/61", "30/61", "0/0"] {
+            assert!(
+                format!("point_thresholds={raw}")
+                    .parse::<GameOptions>()
+                    .is_err(),
+                "{raw} accepted"
+            );
+        }
+    }
+}
@@ -1,28 +1,30 @@
 use std::{
     cmp::Ordering,
+    collections::HashMap,
     fmt::{self, Display},
-    iter::Sum,
+    iter::{FilterMap, Sum},
     ops::{Deref, DerefMut, Index, IndexMut},
+    slice,
     str::FromStr,
 };
 
-use mirabel::{
-    error::{Error, ErrorCode, Result},
-    game::{move_code, player_id, MoveCode, MOVE_NONE, PLAYER_NONE, PLAYER_RAND},
-};
+use mirabel::game::{move_code, player_id, MoveCode, MOVE_NONE, PLAYER_NONE, PLAYER_RAND};
 use nom::{
     branch::alt,
     bytes::complete::{tag, tag_no_case},
-    character::complete::{char, space0, space1},
-    combinator::{cut, eof, map, opt, value},
+    character::complete::{char, space0, space1, u16 as parse_u16},
+    combinator::{cut, eof, map, opt, value, verify},
     error::{context, convert_error, VerboseError},
     sequence::{delimited, pair, preceded, separated_pair, terminated, tuple},
     Finish,
 };
 
-type IResult<I, O> = nom::IResult<I, O, VerboseError<I>>;
+use crate::error::{SkatError, SkatResult};
 
-#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+pub(crate) type IResult<I, O> = nom::IResult<I, O, VerboseError<I>>;
+
+#[derive(Clone, Copy, PartialEq, Eq, Hash, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub(crate) enum Player {
     Forehand,
     Middlehand,
@@ -32,10 +34,22 @@ pub(crate) enum Player {
 impl Player {
     pub(crate) const COUNT: usize = 3;
 
-    const fn all() -> [Self; Self::COUNT] {
+    pub(crate) const fn all() -> [Self; Self::COUNT] {
         [Self::Forehand, Self::Middlehand, Self::Rearhand]
     }
 
+    /// Return all players in the order of play beginning with `start`.
+    pub(crate) const fn all_rotated_from(start: Self) -> [Self; Self::COUNT] {
+        let all = Self::all();
+        let mut rotated = all;
+        let mut i = 0;
+        while i < Self::COUNT {
+            rotated[i] = all[(start as usize + i) % Self::COUNT];
+            i += 1;
+        }
+        rotated
+    }
+
     /// Return the other two players.
     pub const fn others(&self) -> [Self; Self::COUNT - 1] {
         let all = Self::all();
@@ -54,19 +68,44 @@ impl Player {
     pub(crate) fn next(&self) -> Player {
         Self::all()[(*self as usize + 1) % Self::COUNT]
     }
+
+    /// Describes the role together with the physical seat like
+    /// `forehand (seat 1)`.
+    ///
+    /// `seat_offset` is the zero-based seat of the forehand.
+    pub(crate) fn display_seat(self, seat_offset: u8) -> String {
+        let seat = (self as usize + usize::from(seat_offset)) % Self::COUNT + 1;
+        format!("{self} (seat {seat})")
+    }
+
+    /// Parses the name of a player like `forehand` ignoring case.
+    pub(crate) fn parse(input: &str) -> IResult<&str, Self> {
+        context(
+            "player",
+            alt((
+                value(Self::Forehand, tag_no_case("forehand")),
+                value(Self::Middlehand, tag_no_case("middlehand")),
+                value(Self::Rearhand, tag_no_case("rearhand")),
+            )),
+        )(input)
+    }
 }
 
-impl From<player_id> for Player {
+impl TryFrom<player_id> for Player {
+    type Error = SkatError;
+
     /// Convert a [`player_id`] to [`Self`].
     ///
-    /// # Panics
-    /// Panics if the id is out of range.
-    fn from(value: player_id) -> Self {
+    /// Fails for [`PLAYER_NONE`], [`PLAYER_RAND`], and other ids out of range.
+    fn try_from(value: player_id) -> std::result::Result<Self, Self::Error> {
         #[allow(clippy::assertions_on_constants)]
         const _: () = assert!(0 == PLAYER_NONE);
         #[allow(clippy::assertions_on_constants)]
         const _: () = assert!(PLAYER_RAND > 3);
-        Self::all()[usize::from(value.checked_sub(1).unwrap())]
+        value
+            .checked_sub(1)
+            .and_then(|i| Self::all().get(usize::from(i)).copied())
+            .ok_or(SkatError::NoSeat)
     }
 }
 
@@ -94,7 +133,7 @@ impl Display for Player {
 ///
 /// [`Ord`] follows the ordering of a Null game with [`Self::Ace`] being the
 /// lowest.
-#[derive(Clone, Copy, PartialEq, Eq, Debug, PartialOrd, Ord)]
+#[derive(Clone, Copy, PartialEq, Eq, Hash, Debug, PartialOrd, Ord)]
 pub(crate) enum CardValue {
     Ace,
     King,
@@ -142,10 +181,38 @@ impl CardValue {
         }
     }
 
+    /// Returns the German name of this card value.
+    pub(crate) fn german_name(self) -> &'static str {
+        match self {
+            CardValue::Num7 => "Sieben",
+            CardValue::Num8 => "Acht",
+            CardValue::Num9 => "Neun",
+            CardValue::Jack => "Bube",
+            CardValue::Queen => "Dame",
+            CardValue::King => "König",
+            CardValue::Num10 => "Zehn",
+            CardValue::Ace => "Ass",
+        }
+    }
+
+    /// Returns the English name of this card value.
+    pub(crate) fn english_name(self) -> &'static str {
+        match self {
+            CardValue::Num7 => "7",
+            CardValue::Num8 => "8",
+            CardValue::Num9 => "9",
+            CardValue::Jack => "jack",
+            CardValue::Queen => "queen",
+            CardValue::King => "king",
+            CardValue::Num10 => "10",
+            CardValue::Ace => "ace",
+        }
+    }
+
     /// Parses a card value.
     ///
-    /// The input could be either `7`, `8`, `9`, `J`, `Q`, `K`, `10`, or `A`
-    /// ignoring case.
+    /// The input could be either `7`, `8`, `9`, `J`, `Q`, `K`, `10` or `T`,
+    /// or `A` ignoring case.
     fn parse(input: &str) -> IResult<&str, Self> {
         context(
             "card value",
@@ -157,12 +224,38 @@ impl CardValue {
                 value(Self::Queen, tag_no_case("Q")),
                 value(Self::King, tag_no_case("K")),
                 value(Self::Num10, tag("10")),
+                value(Self::Num10, tag_no_case("T")),
                 value(Self::Ace, tag_no_case("A")),
             )),
         )(input)
     }
 }
 
+impl From<CardValue> for u8 {
+    /// Encodes the value by its position in the Null ordering.
+    fn from(value: CardValue) -> Self {
+        value as u8
+    }
+}
+
+impl TryFrom<u8> for CardValue {
+    type Error = SkatError;
+
+    fn try_from(value: u8) -> std::result::Result<Self, Self::Error> {
+        Ok(match value {
+            0 => Self::Ace,
+            1 => Self::King,
+            2 => Self::Queen,
+            3 => Self::Jack,
+            4 => Self::Num10,
+            5 => Self::Num9,
+            6 => Self::Num8,
+            7 => Self::Num7,
+            8.. => return Err(SkatError::CardValueTooHigh),
+        })
+    }
+}
+
 impl Display for CardValue {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
         write!(
@@ -182,7 +275,8 @@ impl Display for CardValue {
     }
 }
 
-#[derive(Clone, Copy, PartialEq, Eq, Debug, PartialOrd, Ord)]
+#[derive(Clone, Copy, PartialEq, Eq, Hash, Debug, PartialOrd, Ord)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub(crate) enum Suit {
     Clubs,
     Spades,
@@ -199,6 +293,26 @@ impl Suit {
         [Self::Clubs, Self::Spades, Self::Hearts, Self::Diamonds]
     }
 
+    /// Returns the German name of this suit.
+    pub(crate) fn german_name(self) -> &'static str {
+        match self {
+            Suit::Clubs => "Kreuz",
+            Suit::Spades => "Pik",
+            Suit::Hearts => "Herz",
+            Suit::Diamonds => "Karo",
+        }
+    }
+
+    /// Returns the English name of this suit.
+    pub(crate) fn english_name(self) -> &'static str {
+        match self {
+            Suit::Clubs => "clubs",
+            Suit::Spades => "spades",
+            Suit::Hearts => "hearts",
+            Suit::Diamonds => "diamonds",
+        }
+    }
+
     /// Parses a suit.
     ///
     /// The input could be either `C`, `S`, `H`, or `D` ignoring case.
@@ -215,27 +329,60 @@ impl Suit {
     }
 }
 
+impl From<Suit> for u8 {
+    fn from(value: Suit) -> Self {
+        value as u8
+    }
+}
+
+impl TryFrom<u8> for Suit {
+    type Error = SkatError;
+
+    fn try_from(value: u8) -> std::result::Result<Self, Self::Error> {
+        Ok(match value {
+            0 => Self::Clubs,
+            1 => Self::Spades,
+            2 => Self::Hearts,
+            3 => Self::Diamonds,
+            4.. => return Err(SkatError::SuitTooHigh),
+        })
+    }
+}
+
 impl Display for Suit {
+    /// Writes the suit as a letter or as a symbol like `♣` in the alternate
+    /// form.
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
-        write!(
-            f,
-            "{}",
-            match self {
-                Suit::Clubs => "C",
-                Suit::Spades => "S",
-                Suit::Hearts => "H",
-                Suit::Diamonds => "D",
-            }
-        )
+        let (letter, symbol) = match self {
+            Suit::Clubs => ("C", "♣"),
+            Suit::Spades => ("S", "♠"),
+            Suit::Hearts => ("H", "♥"),
+            Suit::Diamonds => ("D", "♦"),
+        };
+        write!(f, "{}", if f.alternate() { symbol } else { letter })
     }
 }
 
 // FIXME: Fit into a single byte.
-#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+#[derive(Clone, Copy, PartialEq, Eq, Hash, Debug)]
 pub(crate) struct Card(CardValue, Suit);
 
 impl Card {
     pub(crate) const COUNT: usize = Suit::COUNT * CardValue::COUNT;
+    /// The sum of the points of all cards.
+    pub(crate) const POINTS_TOTAL: u8 = {
+        const TOTAL: u8 = 120;
+
+        let all = Card::all();
+        let mut sum = 0;
+        let mut i = 0;
+        while i < Card::COUNT {
+            sum += all[i].points();
+            i += 1;
+        }
+        assert!(sum == TOTAL, "card points do not add up");
+        TOTAL
+    };
     /// The number of bits needed to encode a [`Self`].
     const BITS: u32 = count_bits(Self::COUNT);
 
@@ -254,11 +401,54 @@ impl Card {
         cards
     }
 
+    /// Returns all cards paired with their [`Self::index()`] for setting up
+    /// lookup tables.
+    pub(crate) const fn all_with_index() -> [(Self, usize); Self::COUNT] {
+        let all = Self::all();
+        let mut result = [(all[0], 0); Self::COUNT];
+        let mut i = 0;
+        while i < Self::COUNT {
+            result[i] = (all[i], all[i].index());
+            i += 1;
+        }
+        result
+    }
+
+    /// Returns all cards grouped by suit for rendering.
+    ///
+    /// The suits and values follow the order of [`Suit::all()`] and
+    /// [`CardValue::all()`] respectively.
+    pub(crate) const fn deck_by_suit() -> [[Self; CardValue::COUNT]; Suit::COUNT] {
+        let mut deck = [[Self(CardValue::Num7, Suit::Clubs); CardValue::COUNT]; Suit::COUNT];
+        let mut suit = 0;
+        while suit < Suit::COUNT {
+            let mut value = 0;
+            while value < CardValue::COUNT {
+                deck[suit][value] = Self(CardValue::all()[value], Suit::all()[suit]);
+                value += 1;
+            }
+            suit += 1;
+        }
+        deck
+    }
+
     /// Returns the index of `self` into [`Self::all()`].
     pub(crate) const fn index(&self) -> usize {
         self.0 as usize * Suit::COUNT + self.1 as usize
     }
 
+    /// Returns the card points of `self`.
+    pub(crate) const fn points(self) -> u8 {
+        match self.0 {
+            CardValue::Ace => 11,
+            CardValue::Num10 => 10,
+            CardValue::King => 4,
+            CardValue::Queen => 3,
+            CardValue::Jack => 2,
+            _ => 0,
+        }
+    }
+
     /// Parses a card value followed by its suit.
     pub(crate) fn parse(input: &str) -> IResult<&str, Self> {
         context(
@@ -297,32 +487,88 @@ impl Card {
         }
     }
 
+    /// Orders the cards by their rank under the `declaration` with the
+    /// highest rank being the lowest.
+    ///
+    /// Trumps come first and ties are broken by [`Self::cmp()`] or
+    /// [`Self::cmp_null()`] for Null games.
+    pub(crate) fn cmp_for_game(&self, other: &Card, declaration: Declaration) -> Ordering {
+        let self_trump = matches!(self.trump_suit(declaration), TrumpSuit::Trump);
+        let other_trump = matches!(other.trump_suit(declaration), TrumpSuit::Trump);
+        other_trump.cmp(&self_trump).then_with(|| {
+            if declaration.is_null() {
+                self.cmp_null(other)
+            } else {
+                self.cmp(other)
+            }
+        })
+    }
+
     pub(crate) fn trump_suit(&self, declaration: Declaration) -> TrumpSuit {
         match declaration {
-            Declaration::Normal(_, _) if matches!(self.0, CardValue::Jack) => TrumpSuit::Trump,
+            Declaration::Normal(_, _) | Declaration::Ramsch
+                if matches!(self.0, CardValue::Jack) =>
+            {
+                TrumpSuit::Trump
+            }
             Declaration::Normal(NormalMode::Color(suit), _) if suit == self.1 => TrumpSuit::Trump,
             _ => TrumpSuit::Color(self.1),
         }
     }
+
+    /// Does `self` beat `other` in a trick in which `led` was played first.
+    ///
+    /// A card which neither follows `led` nor is a trump never beats another
+    /// card.
+    pub(crate) fn beats(&self, other: &Card, led: Card, declaration: Declaration) -> bool {
+        let self_suit = self.trump_suit(declaration);
+        let other_suit = other.trump_suit(declaration);
+        if !matches!(self_suit, TrumpSuit::Trump) && self_suit != led.trump_suit(declaration) {
+            return false;
+        }
+        if self_suit == other_suit {
+            let ordering = if declaration.is_null() {
+                self.cmp_null(other)
+            } else {
+                self.cmp(other)
+            };
+            matches!(ordering, Ordering::Less)
+        } else if matches!(self_suit, TrumpSuit::Trump) {
+            true
+        } else {
+            // Only `self` follows the led suit unless `other` is a trump.
+            !matches!(other_suit, TrumpSuit::Trump)
+        }
+    }
+}
+
+impl Ord for Card {
+    /// Canonical ordering by [`Self::index()`].
+    ///
+    /// This is unrelated to the rank of the cards in any particular game.
+    /// Use [`Card::cmp()`] or [`Card::cmp_null()`] for that.
+    fn cmp(&self, other: &Self) -> Ordering {
+        self.index().cmp(&other.index())
+    }
+}
+
+impl PartialOrd for Card {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(Ord::cmp(self, other))
+    }
 }
 
 impl Sum<Card> for u8 {
     fn sum<I: Iterator<Item = Card>>(iter: I) -> Self {
-        iter.map(|card| match card.0 {
-            CardValue::Ace => 11,
-            CardValue::Num10 => 10,
-            CardValue::King => 4,
-            CardValue::Queen => 3,
-            CardValue::Jack => 2,
-            _ => 0,
-        })
-        .sum()
+        iter.map(Card::points).sum()
     }
 }
 
 impl Display for Card {
+    /// The alternate form uses suit symbols.
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
-        write!(f, "{}{}", self.0, self.1)
+        write!(f, "{}", self.0)?;
+        self.1.fmt(f)
     }
 }
 
@@ -343,36 +589,82 @@ impl From<Card> for MoveCode {
     }
 }
 
+impl From<Card> for u8 {
+    /// Packs the card into a single byte as `value << 2 | suit`.
+    ///
+    /// This coincides with [`Card::index()`].
+    fn from(value: Card) -> Self {
+        #[allow(clippy::assertions_on_constants)]
+        const _: () = assert!(Suit::BITS == 2);
+
+        u8::from(value.0) << Suit::BITS | u8::from(value.1)
+    }
+}
+
+impl TryFrom<u8> for Card {
+    type Error = SkatError;
+
+    /// Inverse of the conversion into [`u8`].
+    fn try_from(value: u8) -> std::result::Result<Self, Self::Error> {
+        Ok(Self(
+            (value >> Suit::BITS).try_into()?,
+            (value & ((1 << Suit::BITS) - 1)).try_into()?,
+        ))
+    }
+}
+
 impl TryFrom<move_code> for Card {
-    type Error = Error;
+    type Error = SkatError;
 
-    fn try_from(value: move_code) -> Result<Self> {
+    fn try_from(value: move_code) -> std::result::Result<Self, Self::Error> {
         usize::try_from(value)
             .ok()
             .and_then(|v| Card::all().get(v).cloned())
-            .ok_or_else(|| {
-                Error::new_static(ErrorCode::InvalidMove, "card value in move too high\0")
-            })
+            .ok_or(SkatError::CardValueTooHigh)
     }
 }
 
 impl FromStr for Card {
-    type Err = Error;
+    type Err = SkatError;
 
     /// Parses into a [`Self`] like [`Self::parse()`] but with trimming.
     fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
         Ok(terminated(delimited(space0, Card::parse, space0), eof)(s)
             .finish()
-            .map_err(|e| {
-                Error::new_dynamic(
-                    ErrorCode::InvalidInput,
-                    format!("failed to parse card:\n{}", convert_error(s, e)),
-                )
-            })?
+            .map_err(|e| SkatError::Parse("card", convert_error(s, e)))?
             .1)
     }
 }
 
+/// Parses a [`Card`] like [`Card::from_str()`] but returns a structured error.
+#[cfg_attr(not(test), allow(dead_code))]
+pub(crate) fn parse_card_detailed(s: &str) -> std::result::Result<Card, crate::error::ParseError> {
+    terminated(delimited(space0, Card::parse, space0), eof)(s)
+        .finish()
+        .map(|(_, card)| card)
+        .map_err(|e| crate::error::ParseError::from_verbose(s, e))
+}
+
+/// Displays a [`Card`] with its German name like `Bube Kreuz`.
+pub(crate) struct GermanCard(pub(crate) Card);
+
+impl Display for GermanCard {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let Card(value, suit) = self.0;
+        write!(f, "{} {}", value.german_name(), suit.german_name())
+    }
+}
+
+/// Displays a [`Card`] with its English name like `jack of clubs`.
+pub(crate) struct EnglishCard(pub(crate) Card);
+
+impl Display for EnglishCard {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let Card(value, suit) = self.0;
+        write!(f, "{} of {}", value.english_name(), suit.english_name())
+    }
+}
+
 /// This represents a card which can have a known value or a hidden one.
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
 pub(crate) enum OptCard {
@@ -474,7 +766,7 @@ impl From<OptCard> for MoveCode {
 }
 
 impl TryFrom<move_code> for OptCard {
-    type Error = Error;
+    type Error = SkatError;
 
     fn try_from(value: move_code) -> std::result::Result<Self, Self::Error> {
         Ok(if value == Self::HIDDEN {
@@ -486,19 +778,14 @@ impl TryFrom<move_code> for OptCard {
 }
 
 impl FromStr for OptCard {
-    type Err = Error;
+    type Err = SkatError;
 
     /// Parses into a [`Self`] like [`Self::parse()`] but with trimming.
     fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
         Ok(
             terminated(delimited(space0, OptCard::parse, space0), eof)(s)
                 .finish()
-                .map_err(|e| {
-                    Error::new_dynamic(
-                        ErrorCode::InvalidInput,
-                        format!("failed to parse optional card:\n{}", convert_error(s, e)),
-                    )
-                })?
+                .map_err(|e| SkatError::Parse("optional card", convert_error(s, e)))?
                 .1,
         )
     }
@@ -506,6 +793,11 @@ impl FromStr for OptCard {
 
 /// A vector of [`OptCard`]s with helper functionality.
 #[derive(PartialEq, Eq, Debug, Clone, Default)]
+#[cfg_attr(
+    feature = "serde",
+    derive(serde::Serialize, serde::Deserialize),
+    serde(transparent)
+)]
 pub(crate) struct CardVec(Vec<OptCard>);
 
 impl CardVec {
@@ -513,10 +805,48 @@ impl CardVec {
         self.iter().cloned().flatten()
     }
 
-    /// Sort in-place respecting whether this is a Null game or not.
-    fn sort(&mut self, null: bool) {
+    pub(crate) fn hidden_count(&self) -> usize {
+        self.iter().filter(|c| matches!(c, OptCard::Hidden)).count()
+    }
+
+    /// Sort in-place according to the ordering of the `declaration`.
+    ///
+    /// Hidden cards are put last.
+    pub(crate) fn sort_for_declaration(&mut self, declaration: Declaration) {
+        let null = declaration.is_null();
         self.sort_by(|a, b| a.cmp(b, null));
     }
+
+    /// Returns the highest ranked known card under the `declaration`.
+    ///
+    /// See [`Card::cmp_for_game()`].
+    #[cfg_attr(not(test), allow(dead_code))]
+    pub(crate) fn highest_card_for_game(&self, declaration: Declaration) -> Option<Card> {
+        self.iter_known()
+            .min_by(|a, b| a.cmp_for_game(b, declaration))
+    }
+
+    /// Returns the lowest ranked known card under the `declaration`.
+    ///
+    /// See [`Card::cmp_for_game()`].
+    #[cfg_attr(not(test), allow(dead_code))]
+    pub(crate) fn lowest_card_for_game(&self, declaration: Declaration) -> Option<Card> {
+        self.iter_known()
+            .max_by(|a, b| a.cmp_for_game(b, declaration))
+    }
+}
+
+impl<'a> IntoIterator for &'a CardVec {
+    type Item = Card;
+    type IntoIter = FilterMap<slice::Iter<'a, OptCard>, fn(&OptCard) -> Option<Card>>;
+
+    /// Iterates over the known cards only, skipping hidden ones.
+    ///
+    /// Hidden cards can still be examined through the [`Deref`] to
+    /// [`Vec<OptCard>`].
+    fn into_iter(self) -> Self::IntoIter {
+        self.0.iter().filter_map(|c| c.ok())
+    }
 }
 
 impl Deref for CardVec {
@@ -533,21 +863,44 @@ impl DerefMut for CardVec {
     }
 }
 
+impl From<Vec<OptCard>> for CardVec {
+    fn from(value: Vec<OptCard>) -> Self {
+        Self(value)
+    }
+}
+
+impl From<Vec<Card>> for CardVec {
+    /// Wraps every card in [`OptCard::Known`].
+    fn from(value: Vec<Card>) -> Self {
+        Self(value.into_iter().map(OptCard::from).collect())
+    }
+}
+
+impl From<&[Card]> for CardVec {
+    /// Wraps every card in [`OptCard::Known`].
+    fn from(value: &[Card]) -> Self {
+        Self(value.iter().copied().map(OptCard::from).collect())
+    }
+}
+
 impl Display for CardVec {
     /// Write a space separated list of cards.
+    ///
+    /// The alternate form uses suit symbols.
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
         for (i, card) in self.iter().enumerate() {
             if i > 0 {
                 write!(f, " ")?;
             }
-            write!(f, "{card}")?;
+            card.fmt(f)?;
         }
         Ok(())
     }
 }
 
 // FIXME: Replace vectors with some array vectors.
-#[derive(Default, Clone, Debug)]
+#[derive(Default, Clone, Debug, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub(crate) struct CardStruct {
     /// # Invariants
     /// At most [`Self::HAND_SIZE`]`+`[`Self::SKAT_SIZE`] cards per hand.
@@ -564,9 +917,9 @@ pub(crate) struct CardStruct {
 }
 
 impl CardStruct {
-    const HAND_SIZE: usize = 10;
+    pub(crate) const HAND_SIZE: usize = 10;
     pub(crate) const SKAT_SIZE: usize = 2;
-    const TRICK_SIZE: usize = 3;
+    pub(crate) const TRICK_SIZE: usize = 3;
 
     pub(crate) fn iter(&self) -> impl Iterator<Item = Card> + '_ {
         self.hands
@@ -583,10 +936,52 @@ impl CardStruct {
             unknown[card.index()] = false;
         }
 
-        Card::all()
+        Card::all_with_index()
             .into_iter()
-            .zip(unknown.into_iter())
-            .filter_map(|(c, u)| u.then_some(c))
+            .filter_map(move |(c, i)| unknown[i].then_some(c))
+    }
+
+    /// Returns the cards which are out of play and thus seen by everybody.
+    ///
+    /// These are the cards of the current trick and the played cards.
+    /// See [`crate::Skat::seen_cards()`] for the view of a single player.
+    #[cfg_attr(not(test), allow(dead_code))]
+    pub(crate) fn seen_cards(&self) -> impl Iterator<Item = Card> + '_ {
+        self.trick
+            .iter()
+            .chain(self.played.iter().flatten())
+            .cloned()
+    }
+
+    /// Returns every card slot together with its exact position.
+    ///
+    /// The order is deterministic: hands from forehand to rearhand, the Skat,
+    /// the current trick, and finally the played cards per player.
+    #[cfg_attr(not(test), allow(dead_code))]
+    pub(crate) fn iter_all_positions(&self) -> impl Iterator<Item = (CardLocation, OptCard)> + '_ {
+        let hands = Player::all().into_iter().flat_map(move |p| {
+            self[p]
+                .iter()
+                .enumerate()
+                .map(move |(i, &c)| (CardLocation::Hand(p, i), c))
+        });
+        let skat = self
+            .skat
+            .iter()
+            .enumerate()
+            .map(|(i, &c)| (CardLocation::Skat(i), c));
+        let trick = self
+            .trick
+            .iter()
+            .enumerate()
+            .map(|(i, &c)| (CardLocation::Trick(i), c.into()));
+        let played = Player::all().into_iter().flat_map(move |p| {
+            self.played[p as usize]
+                .iter()
+                .enumerate()
+                .map(move |(i, &c)| (CardLocation::Played(p, i), c.into()))
+        });
+        hands.chain(skat).chain(trick).chain(played)
     }
 
     /// Give the `target` a `card`.
@@ -605,7 +1000,7 @@ impl CardStruct {
     /// It then searches for the `card` in the `player`s hand and removes it.
     /// If it was not found, it removes a hidden card or, if there is no hidden
     /// card, it returns an error.
-    pub(crate) fn take(&mut self, player: Player, card: OptCard) -> Result<()> {
+    pub(crate) fn take(&mut self, player: Player, card: OptCard) -> SkatResult<()> {
         if matches!(card, OptCard::Hidden) {
             for card in self[player].iter_mut() {
                 *card = OptCard::Hidden;
@@ -618,10 +1013,7 @@ impl CardStruct {
                     .iter()
                     .enumerate()
                     .find(|(_, c)| matches!(c, OptCard::Hidden))
-                    .ok_or(Error::new_static(
-                        ErrorCode::InvalidMove,
-                        "cannot take this card for this player\0",
-                    ))?
+                    .ok_or(SkatError::CannotTake)?
                     .0
             }
         };
@@ -629,15 +1021,97 @@ impl CardStruct {
         Ok(())
     }
 
+    /// Deal all cards reproducibly shuffled according to the `seed`.
+    ///
+    /// This uses a Fisher-Yates shuffle driven by an _xorshift64_ generator.
+    #[cfg_attr(not(test), allow(dead_code))]
+    pub(crate) fn deal_from_seed(seed: u64) -> Self {
+        // Xorshift gets stuck at zero.
+        let mut state = if seed == 0 {
+            0x9e37_79b9_7f4a_7c15
+        } else {
+            seed
+        };
+        let mut next = move || {
+            state ^= state << 13;
+            state ^= state >> 7;
+            state ^= state << 17;
+            state
+        };
+
+        let mut deck = Card::all();
+        for i in (1..deck.len()).rev() {
+            let j = (next() % (i as u64 + 1)) as usize;
+            deck.swap(i, j);
+        }
+
+        let mut cards = Self::default();
+        for (dealt, card) in deck.into_iter().enumerate() {
+            let target = crate::deal_to(dealt as u8).expect("deck has exactly 32 cards");
+            cards.give(target, card.into());
+        }
+        cards
+    }
+
+    /// Remove all cards while keeping the allocations.
+    pub(crate) fn clear(&mut self) {
+        for cards in self.hands.iter_mut() {
+            cards.clear();
+        }
+        self.skat.clear();
+        self.trick.clear();
+        for cards in self.played.iter_mut() {
+            cards.clear();
+        }
+    }
+
+    /// Move the known `card` from the hand of `from` to the hand of `to`.
+    ///
+    /// Nothing is changed if `from` cannot give away the `card`.
+    #[cfg_attr(not(test), allow(dead_code))]
+    pub(crate) fn transfer_card(&mut self, from: Player, to: Player, card: Card) -> SkatResult<()> {
+        self.take(from, OptCard::Known(card))?;
+        self.give(Some(to), OptCard::Known(card));
+        Ok(())
+    }
+
     /// Count the number of managed cards.
     ///
     /// This is useful in the dealing phase to find the number of dealt cards.
     pub(crate) fn count(&self) -> u8 {
-        let count: usize = self.hands.iter().map(|v| v.len()).sum::<usize>()
-            + self.skat.len()
-            + self.trick.len()
-            + self.played.iter().map(|t| t.len()).sum::<usize>();
-        count.try_into().expect("too many cards in card structure")
+        self.counts().total()
+    }
+
+    /// Count the managed cards per location.
+    pub(crate) fn counts(&self) -> Counts {
+        let len = |n: usize| u8::try_from(n).expect("too many cards in card structure");
+        Counts {
+            hands: self.hands.each_ref().map(|h| len(h.len())),
+            skat: len(self.skat.len()),
+            trick: len(self.trick.len()),
+            played: self.played.each_ref().map(|p| len(p.len())),
+        }
+    }
+
+    /// Count the number of [`OptCard::Hidden`] cards in the hands and the
+    /// Skat.
+    pub(crate) fn hidden_card_count(&self) -> usize {
+        self.hands
+            .iter()
+            .chain([&self.skat])
+            .flat_map(|v| v.iter())
+            .filter(|c| matches!(c, OptCard::Hidden))
+            .count()
+    }
+
+    /// Count the number of [`OptCard::Known`] cards in the hands and the Skat.
+    #[cfg_attr(not(test), allow(dead_code))]
+    pub(crate) fn open_card_count(&self) -> usize {
+        self.hands
+            .iter()
+            .chain([&self.skat])
+            .flat_map(|v| v.iter_known())
+            .count()
     }
 
     /// Redact hidden information like hands and the Skat.
@@ -657,14 +1131,53 @@ impl CardStruct {
         }
     }
 
-    /// Sort cards in-place.
+    /// Replace hidden Skat cards with the only cards left unknown.
     ///
-    /// `null` specified whether to sort for a Null game or for a normal game.
-    pub(crate) fn sort(&mut self, null: bool) {
+    /// This is only done if no hand contains hidden cards as the Skat cannot
+    /// be determined otherwise.
+    pub(crate) fn reveal_skat(&mut self) {
+        if self
+            .hands
+            .iter()
+            .flat_map(|h| h.iter())
+            .any(|c| matches!(c, OptCard::Hidden))
+        {
+            return;
+        }
+        let mut unknown = self.iter_unknown().collect::<Vec<_>>().into_iter();
+        for card in self
+            .skat
+            .iter_mut()
+            .filter(|c| matches!(c, OptCard::Hidden))
+        {
+            if let Some(known) = unknown.next() {
+                *card = known.into();
+            }
+        }
+    }
+
+    /// Sort cards in-place according to the ordering of the `declaration`.
+    #[cfg_attr(not(test), allow(dead_code))]
+    pub(crate) fn sort_for_declaration(&mut self, declaration: Declaration) {
         for hand in self.hands.iter_mut() {
-            hand.sort(null);
+            hand.sort_for_declaration(declaration);
         }
-        self.skat.sort(null);
+        self.skat.sort_for_declaration(declaration);
+    }
+
+    /// Returns a sorted copy of the hand of `player` for displaying.
+    ///
+    /// Without a `declaration`, the cards are sorted like in a Grand game.
+    pub(crate) fn hand_for_display(
+        &self,
+        player: Player,
+        declaration: Option<Declaration>,
+    ) -> CardVec {
+        let mut hand = self[player].clone();
+        hand.sort_for_declaration(
+            declaration.unwrap_or(Declaration::Normal(NormalMode::Grand, GameLevel::Normal)),
+        );
+        hand
     }
 
     /// Returns the [`Card`]s the [`Player`] is allowed to play.
@@ -682,38 +1195,199 @@ impl CardStruct {
             }
         }
 
-        let Some(first) = self.trick.get(0) else { return allowed; };
-        let follow = first.trump_suit(declaration);
-        let must_follow = allowed.iter().any(|c| c.trump_suit(declaration) == follow);
-        if must_follow {
-            allowed.retain(|c| c.trump_suit(declaration) == follow)
+        let Some(follow) = self.current_trick_lead_suit(declaration) else {
+            return allowed;
+        };
+        let hand: CardSet = allowed.iter().copied().collect();
+        let trumps = hand.trumps_under(declaration);
+        let following = match follow {
+            TrumpSuit::Trump => trumps,
+            TrumpSuit::Color(suit) => hand.of_suit(suit).difference(trumps),
+        };
+        if !following.is_empty() {
+            allowed.retain(|&c| following.contains(c))
         }
         allowed
     }
 
+    /// Returns the suit which must be followed in the current trick or
+    /// [`None`] if the next player leads.
+    pub(crate) fn current_trick_lead_suit(&self, declaration: Declaration) -> Option<TrumpSuit> {
+        self.trick.first().map(|c| c.trump_suit(declaration))
+    }
+
     pub(crate) fn winner(&self, declaration: Declaration) -> usize {
-        let mut w = 0;
-        for (c, curr) in self.trick.iter().enumerate().skip(1) {
-            let winner = self.trick[w];
-            let curr_suit = curr.trump_suit(declaration);
-            let winner_suit = self.trick[w].trump_suit(declaration);
-            let better = matches!(
-                if declaration.is_null() {
-                    curr.cmp_null(&winner)
-                } else {
-                    curr.cmp(&winner)
-                },
-                Ordering::Less
-            );
+        trick_winner(&self.trick, declaration)
+    }
 
-            if (better && curr_suit == winner_suit)
-                || (matches!(curr_suit, TrumpSuit::Trump)
-                    && !matches!(winner_suit, TrumpSuit::Trump))
-            {
-                w = c;
+    /// Returns the card currently winning the trick or [`None`] if the trick
+    /// is empty.
+    pub(crate) fn winning_card_in_trick(&self, declaration: Declaration) -> Option<Card> {
+        self.trick.get(self.winner(declaration)).copied()
+    }
+
+    /// Returns the index into [`Self::trick`] and the [`Player`] of the card
+    /// currently winning the trick.
+    ///
+    /// `player` must be the player to play the next card.
+    /// Returns [`None`] if the trick is empty.
+    pub(crate) fn current_trick_leader(
+        &self,
+        player: Player,
+        declaration: Declaration,
+    ) -> Option<(usize, Player)> {
+        if self.trick.is_empty() {
+            return None;
+        }
+        let w = self.winner(declaration);
+        let mut leader = player;
+        for _ in 0..(Player::COUNT - self.trick.len() + w) {
+            leader = leader.next();
+        }
+        Some((w, leader))
+    }
+
+    /// Returns `true` if the Skat holds both of its cards and they are known.
+    pub(crate) fn skat_is_fully_known(&self) -> bool {
+        self.skat.len() == Self::SKAT_SIZE
+            && self.skat.iter().all(|c| matches!(c, OptCard::Known(_)))
+    }
+
+    /// Returns the card points neither captured in a completed trick nor lying
+    /// in the current trick.
+    ///
+    /// The played cards are exactly the captured ones, so this matches the
+    /// point tallies of the players.
+    /// The known cards of the Skat already belong to the declarer and are
+    /// only in play if the winner of the last trick takes them as in
+    /// _Ramsch_.
+    pub(crate) fn points_remaining(&self, declaration: Declaration) -> u8 {
+        let captured: u8 = self.played.iter().flatten().copied().sum();
+        let trick: u8 = self.trick.iter().copied().sum();
+        let skat: u8 = if matches!(declaration, Declaration::Ramsch) {
+            0
+        } else {
+            self.skat.iter_known().sum()
+        };
+        Card::POINTS_TOTAL - captured - trick - skat
+    }
+
+    /// Returns where the known `card` is located if anywhere.
+    pub(crate) fn location_of(&self, card: Card) -> Option<Location> {
+        let card = OptCard::from(card);
+        if let Some(player) = Player::all().into_iter().find(|&p| self[p].contains(&card)) {
+            Some(Location::Hand(player))
+        } else if self.skat.contains(&card) {
+            Some(Location::Skat)
+        } else if self.trick.iter().any(|&c| OptCard::from(c) == card) {
+            Some(Location::Trick)
+        } else {
+            Player::all()
+                .into_iter()
+                .find(|&p| {
+                    self.played[p as usize]
+                        .iter()
+                        .any(|&c| OptCard::from(c) == card)
+                })
+                .map(Location::Played)
+        }
+    }
+
+    /// Are the `known_positions` of cards consistent with `self`.
+    ///
+    /// A card which is not known in `self` must fit into a hidden card of the
+    /// hand or Skat it is observed at.
+    #[cfg_attr(not(test), allow(dead_code))]
+    pub(crate) fn consistent_with_visible(
+        &self,
+        known_positions: &HashMap<Card, Location>,
+    ) -> bool {
+        let mut hidden_hands = self.hands.clone().map(|h| h.hidden_count());
+        let mut hidden_skat = self.skat.hidden_count();
+        for (&card, &location) in known_positions {
+            match (self.location_of(card), location) {
+                (Some(actual), _) if actual == location => {}
+                (Some(_), _) => return false,
+                (None, Location::Hand(player)) => {
+                    let Some(hidden) = hidden_hands[player as usize].checked_sub(1) else {
+                        return false;
+                    };
+                    hidden_hands[player as usize] = hidden;
+                }
+                (None, Location::Skat) => {
+                    let Some(hidden) = hidden_skat.checked_sub(1) else {
+                        return false;
+                    };
+                    hidden_skat = hidden;
+                }
+                // Played cards are always known.
+                (None, Location::Trick | Location::Played(_)) => return false,
+            }
+        }
+        true
+    }
+
+    /// Returns the completed tricks with their cards in the order of play and
+    /// their winners.
+    ///
+    /// As [`Self::played`] stores the cards by the player who played them, the
+    /// tricks are replayed starting with the forehand to find their winners.
+    pub(crate) fn completed_tricks(
+        &self,
+        declaration: Declaration,
+    ) -> Vec<([Card; Self::TRICK_SIZE], Player)> {
+        let mut tricks = Vec::new();
+        let mut leader = Player::Forehand;
+        for i in 0..self.played[leader as usize].len() {
+            let Ok(trick): std::result::Result<[Card; Self::TRICK_SIZE], _> =
+                Player::all_rotated_from(leader)
+                    .into_iter()
+                    .filter_map(|p| self.played[p as usize].get(i).copied())
+                    .collect::<Vec<_>>()
+                    .try_into()
+            else {
+                break;
+            };
+            for _ in 0..trick_winner(&trick, declaration) {
+                leader = leader.next();
             }
+            tricks.push((trick, leader));
+        }
+        tricks
+    }
+
+    /// Returns the winner of the previous trick or [`None`] if no trick has
+    /// been completed.
+    pub(crate) fn last_trick_winner(&self, declaration: Declaration) -> Option<Player> {
+        self.completed_tricks(declaration)
+            .last()
+            .map(|&(_, winner)| winner)
+    }
+
+    /// Returns the cards of the previous trick in the order of play.
+    pub(crate) fn last_trick_cards(
+        &self,
+        declaration: Declaration,
+    ) -> Option<[Card; Self::TRICK_SIZE]> {
+        self.completed_tricks(declaration)
+            .last()
+            .map(|&(cards, _)| cards)
+    }
+
+    /// Returns the points of the cards each player won in completed tricks.
+    pub(crate) fn points_by_player(&self, declaration: Declaration) -> [u8; Player::COUNT] {
+        let mut points = [0; Player::COUNT];
+        for (trick, winner) in self.completed_tricks(declaration) {
+            points[winner as usize] += trick.into_iter().sum::<u8>();
         }
-        w
+        debug_assert!(
+            self.hands.iter().any(|h| !h.is_empty())
+                || !self.skat_is_fully_known()
+                || points.iter().sum::<u8>() + self.skat.iter_known().sum::<u8>()
+                    == Card::POINTS_TOTAL,
+            "points of a finished game do not add up"
+        );
+        points
     }
 
     /// Move cards from [`Self::trick`] to [`Self::played`].
@@ -727,6 +1401,57 @@ impl CardStruct {
     }
 }
 
+/// Place where a card can be located.
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+pub(crate) enum Location {
+    Hand(Player),
+    Skat,
+    /// The current trick.
+    Trick,
+    /// Completed tricks stored by the player who played the card.
+    Played(Player),
+}
+
+/// Number of cards per location of a [`CardStruct`].
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub(crate) struct Counts {
+    pub hands: [u8; Player::COUNT],
+    pub skat: u8,
+    pub trick: u8,
+    /// Cards played in completed tricks per player.
+    pub played: [u8; Player::COUNT],
+}
+
+impl Counts {
+    pub(crate) fn total(&self) -> u8 {
+        self.hands.iter().sum::<u8>() + self.skat + self.trick + self.played.iter().sum::<u8>()
+    }
+
+    /// Do the counts respect the invariants of [`CardStruct`].
+    pub(crate) fn within_caps(&self) -> bool {
+        const PER_PLAYER: usize = CardStruct::HAND_SIZE + CardStruct::SKAT_SIZE;
+        self.hands
+            .iter()
+            .chain(&self.played)
+            .all(|&n| usize::from(n) <= PER_PLAYER)
+            && usize::from(self.skat) <= CardStruct::SKAT_SIZE
+            && usize::from(self.trick) < CardStruct::TRICK_SIZE
+            && usize::from(self.total()) <= Card::COUNT
+    }
+}
+
+/// Exact position of a card slot in a [`CardStruct`].
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+pub(crate) enum CardLocation {
+    /// Index into the hand of the player.
+    Hand(Player, usize),
+    Skat(usize),
+    /// Index into the current trick.
+    Trick(usize),
+    /// Index into the cards the player played in completed tricks.
+    Played(Player, usize),
+}
+
 impl Index<Player> for CardStruct {
     type Output = CardVec;
 
@@ -742,30 +1467,35 @@ impl IndexMut<Player> for CardStruct {
 }
 
 impl Display for CardStruct {
+    /// The alternate form uses suit symbols.
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
         for player in Player::all() {
             write!(f, "{player}:")?;
             let hand = &self[player];
             if !hand.is_empty() {
-                write!(f, " {hand}")?;
+                write!(f, " ")?;
+                hand.fmt(f)?;
             }
             let played = &self.played[player as usize];
             if !played.is_empty() {
                 write!(f, " |")?;
                 for card in played {
-                    write!(f, " {card}")?;
+                    write!(f, " ")?;
+                    card.fmt(f)?;
                 }
             }
             writeln!(f)?;
         }
 
-        write!(f, "Skat: {}", self.skat)?;
+        write!(f, "Skat: ")?;
+        self.skat.fmt(f)?;
 
         if !self.trick.is_empty() {
             writeln!(f)?;
             write!(f, "current trick:")?;
             for card in &self.trick {
-                write!(f, " {card}")?;
+                write!(f, " ")?;
+                card.fmt(f)?;
             }
         }
 
@@ -773,7 +1503,8 @@ impl Display for CardStruct {
     }
 }
 
-#[derive(Default, Clone, Copy, Debug)]
+#[derive(Default, Clone, Copy, Debug, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub(crate) enum Declaration {
     /// A normal game (i.e., not a _Null_ game)
     ///
@@ -791,25 +1522,35 @@ pub(crate) enum Declaration {
     NullHand,
     NullOuvert,
     NullOuvertHand,
+    /// Played without a declarer when everybody passed.
+    ///
+    /// Only the jacks are trumps like in a _Grand_ game.
+    /// This cannot be declared.
+    Ramsch,
 }
 
 impl Declaration {
-    const BITS: u32 = max(NormalMode::BITS + GameLevel::BITS, 2) + 1;
+    /// Enough bits for either a normal game or one of the sentinels plus the
+    /// bit marking normal games.
+    const BITS: u32 = max(
+        NormalMode::BITS + GameLevel::BITS,
+        count_bits(Self::RAMSCH as usize + 1),
+    ) + 1;
     const NULL: move_code = 0;
     const NULL_HAND: move_code = 1;
     const NULL_OUVERT: move_code = 2;
     const NULL_OUVERT_HAND: move_code = 3;
+    const RAMSCH: move_code = 4;
 
     /// List all possible declarations.
     ///
     /// If `hand`, assume a _Hand_ game else assume otherwise.
     // FIXME: Replace with fixed-sized vector.
     pub(crate) fn all(hand: bool) -> Vec<Self> {
-        let mut possibilities = if hand {
-            vec![Self::NullHand, Self::NullOuvertHand]
-        } else {
-            vec![Self::Null, Self::NullOuvert]
-        };
+        let mut possibilities = vec![
+            Self::for_null_game(hand, false),
+            Self::for_null_game(hand, true),
+        ];
         for mode in NormalMode::all() {
             for &level in GameLevel::all(hand) {
                 possibilities.push(Self::Normal(mode, level));
@@ -818,13 +1559,24 @@ impl Declaration {
         possibilities
     }
 
+    /// Returns the Null game variant for the given properties.
+    pub(crate) fn for_null_game(hand: bool, ouvert: bool) -> Self {
+        match (hand, ouvert) {
+            (false, false) => Self::Null,
+            (true, false) => Self::NullHand,
+            (false, true) => Self::NullOuvert,
+            (true, true) => Self::NullOuvertHand,
+        }
+    }
+
     pub(crate) fn is_hand(&self) -> bool {
         match self {
-            Declaration::Normal(_, l) => l.is_hand(),
+            Declaration::Normal(_, l) => l.requires_hand(),
             Declaration::Null => false,
             Declaration::NullHand => true,
             Declaration::NullOuvert => false,
             Declaration::NullOuvertHand => true,
+            Declaration::Ramsch => false,
         }
     }
 
@@ -837,41 +1589,95 @@ impl Declaration {
         )
     }
 
+    /// Does this declaration reach the `bid` value when just winning it with
+    /// the number of `matadors`.
+    ///
+    /// Unlike [`Self::allowed()`], this does not count on winning _Schneider_
+    /// or _Schwarz_ unless announced.
+    /// The values of Null games are taken from `null`.
+    pub(crate) fn safe(&self, bid: u16, matadors: &Matadors, null: &NullValues) -> bool {
+        bid <= self.safe_value(matadors, null)
+    }
+
+    /// Returns the game value reached without winning _Schneider_ or
+    /// _Schwarz_ unless announced.
+    ///
+    /// The values of Null games are taken from `null`.
+    pub(crate) fn safe_value(&self, matadors: &Matadors, null: &NullValues) -> u16 {
+        match *self {
+            Declaration::Normal(mode, level) => {
+                let achieved =
+                    u16::from(level.requires_schneider()) + u16::from(level.requires_schwarz());
+                (u16::from(matadors[mode]) + u16::from(level) + achieved) * u16::from(mode)
+            }
+            _ => self.value(null),
+        }
+    }
+
     /// Is this declaration allowed given the `bid` value and number of
     /// `matadors`.
-    pub(crate) fn allowed(&self, bid: u16, matadors: &Matadors) -> bool {
+    ///
+    /// The values of Null games are taken from `null`.
+    pub(crate) fn allowed(&self, bid: u16, matadors: &Matadors, null: &NullValues) -> bool {
         match *self {
             Declaration::Normal(mode, level) => {
                 // Add 2 for possibly playing Schneider and Schwarz.
                 bid <= (u16::from(matadors[mode]) + u16::from(level) + 2) * u16::from(mode)
             }
-            _ => bid <= u16::from(*self),
+            _ => bid <= self.value(null),
         }
     }
 
+    /// Return the base value using `null` for the values of Null games.
+    pub(crate) fn value(&self, null: &NullValues) -> u16 {
+        match *self {
+            Declaration::Normal(mode, _) => mode.into(),
+            Declaration::Null => null.0[0],
+            Declaration::NullHand => null.0[1],
+            Declaration::NullOuvert => null.0[2],
+            Declaration::NullOuvertHand => null.0[3],
+            Declaration::Ramsch => 0,
+        }
+    }
+
+    /// Returns a key for ordering declarations by their base value with the
+    /// Null values from `null` and then by level.
+    pub(crate) fn sort_key(&self, null: &NullValues) -> (u16, Option<GameLevel>) {
+        let level = match self {
+            Declaration::Normal(_, l) => Some(*l),
+            _ => None,
+        };
+        (self.value(null), level)
+    }
+
+    /// Is `value` the value of any game and thus a valid bid.
+    ///
+    /// The values of Null games are taken from `null`.
+    pub(crate) fn is_game_value(value: u16, null: &NullValues) -> bool {
+        null.0.contains(&value)
+            || NormalMode::all().into_iter().any(|mode| {
+                let base = u16::from(mode);
+                // Add 2 for possibly playing Schneider and Schwarz.
+                let max = u16::from(mode.max_matadors()) + u16::from(GameLevel::Ouvert) + 2;
+                value.is_multiple_of(base) && (2..=max).contains(&(value / base))
+            })
+    }
+
     pub(crate) fn parse(input: &str) -> IResult<&str, Self> {
         context(
             "declaration",
             alt((
-                value(
-                    Self::NullOuvertHand,
-                    tuple((
+                map(
+                    preceded(
                         tag_no_case("null"),
-                        space1,
-                        tag_no_case("ouvert"),
-                        space1,
-                        tag_no_case("hand"),
-                    )),
-                ),
-                value(
-                    Self::NullOuvert,
-                    separated_pair(tag_no_case("null"), space1, tag_no_case("ouvert")),
-                ),
-                value(
-                    Self::NullHand,
-                    separated_pair(tag_no_case("null"), space1, tag_no_case("hand")),
+                        pair(
+                            opt(preceded(space1, tag_no_case("ouvert"))),
+                            opt(preceded(space1, tag_no_case("hand"))),
+                        ),
+                    ),
+                    |(o, h)| Self::for_null_game(h.is_some(), o.is_some()),
                 ),
-                value(Self::Null, tag_no_case("null")),
+                value(Self::Ramsch, tag_no_case("ramsch")),
                 map(
                     pair(
                         cut(NormalMode::parse),
@@ -895,29 +1701,27 @@ impl Declaration {
     }
 
     pub(crate) fn is_null(&self) -> bool {
-        !matches!(self, Self::Normal(_, _))
-    }
-
-    pub(crate) fn is_schwarz(&self) -> bool {
         matches!(
             self,
-            Self::Normal(_, GameLevel::Schwarz | GameLevel::Ouvert)
+            Self::Null | Self::NullHand | Self::NullOuvert | Self::NullOuvertHand
         )
     }
 
+    pub(crate) fn is_schwarz(&self) -> bool {
+        matches!(self, Self::Normal(_, l) if l.requires_schwarz())
+    }
+
     pub(crate) fn is_schneider(&self) -> bool {
-        matches!(
-            self,
-            Self::Normal(
-                _,
-                GameLevel::Schneider | GameLevel::Schwarz | GameLevel::Ouvert
-            )
-        )
+        matches!(self, Self::Normal(_, l) if l.requires_schneider())
     }
 }
 
 impl From<Declaration> for move_code {
     fn from(value: Declaration) -> Self {
+        // The sentinels must not have the bit marking normal games set.
+        #[allow(clippy::assertions_on_constants)]
+        const _: () = assert!(Declaration::RAMSCH < 1 << (Declaration::BITS - 1));
+
         match value {
             Declaration::Normal(mode, level) => {
                 (1 << (Declaration::BITS - 1))
@@ -928,12 +1732,13 @@ impl From<Declaration> for move_code {
             Declaration::NullHand => Declaration::NULL_HAND,
             Declaration::NullOuvert => Declaration::NULL_OUVERT,
             Declaration::NullOuvertHand => Declaration::NULL_OUVERT_HAND,
+            Declaration::Ramsch => Declaration::RAMSCH,
         }
     }
 }
 
 impl TryFrom<move_code> for Declaration {
-    type Error = Error;
+    type Error = SkatError;
 
     fn try_from(value: move_code) -> std::result::Result<Self, Self::Error> {
         Ok(match value {
@@ -941,12 +1746,10 @@ impl TryFrom<move_code> for Declaration {
             Self::NULL_HAND => Self::NullHand,
             Self::NULL_OUVERT => Self::NullOuvert,
             Self::NULL_OUVERT_HAND => Self::NullOuvertHand,
+            Self::RAMSCH => Self::Ramsch,
             _ => {
                 if value >> Declaration::BITS != 0 || value & (1 << (Declaration::BITS - 1)) == 0 {
-                    return Err(Error::new_static(
-                        ErrorCode::InvalidMove,
-                        "invalid declaration move\0",
-                    ));
+                    return Err(SkatError::InvalidDeclarationMove);
                 }
                 let level_value = value & ((1 << GameLevel::BITS) - 1);
                 let mode_value = (value >> GameLevel::BITS) & ((1 << NormalMode::BITS) - 1);
@@ -957,15 +1760,27 @@ impl TryFrom<move_code> for Declaration {
 }
 
 impl From<Declaration> for u16 {
-    /// Return the base value.
+    /// Return the base value with the standard values for Null games.
     fn from(value: Declaration) -> Self {
-        match value {
-            Declaration::Normal(mode, _) => mode.into(),
-            Declaration::Null => 23,
-            Declaration::NullHand => 35,
-            Declaration::NullOuvert => 46,
-            Declaration::NullOuvertHand => 59,
-        }
+        value.value(&Default::default())
+    }
+}
+
+impl Ord for Declaration {
+    /// Orders by [`Declaration::sort_key()`] with the standard Null values.
+    ///
+    /// Thus, _Null_ (23) ranks between _Clubs_ and _Grand_ while the other
+    /// _Null_ games rank above _Grand_.
+    /// _Ramsch_ is the lowest.
+    fn cmp(&self, other: &Self) -> Ordering {
+        let null = NullValues::default();
+        self.sort_key(&null).cmp(&other.sort_key(&null))
+    }
+}
+
+impl PartialOrd for Declaration {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
     }
 }
 
@@ -986,11 +1801,55 @@ impl Display for Declaration {
             Declaration::NullHand => write!(f, "Null Hand"),
             Declaration::NullOuvert => write!(f, "Null Ouvert"),
             Declaration::NullOuvertHand => write!(f, "Null Ouvert Hand"),
+            Declaration::Ramsch => write!(f, "Ramsch"),
         }
     }
 }
 
-#[derive(Clone, Copy, Debug)]
+/// The values of the Null games.
+///
+/// These are ordered as _Null_, _Null Hand_, _Null Ouvert_, and
+/// _Null Ouvert Hand_.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub(crate) struct NullValues(pub(crate) [u16; 4]);
+
+impl NullValues {
+    /// Parses four slash-separated values like `23/35/46/59`.
+    ///
+    /// Every value must be positive and must not exceed the highest bid
+    /// [`crate::Skat::MAXIMUM_BID`].
+    pub(crate) fn parse(input: &str) -> IResult<&str, Self> {
+        let value = || verify(parse_u16, |v| (1..=crate::Skat::MAXIMUM_BID).contains(v));
+        context(
+            "Null values",
+            map(
+                tuple((
+                    value(),
+                    preceded(char('/'), value()),
+                    preceded(char('/'), value()),
+                    preceded(char('/'), value()),
+                )),
+                |(a, b, c, d)| Self([a, b, c, d]),
+            ),
+        )(input)
+    }
+}
+
+impl Default for NullValues {
+    fn default() -> Self {
+        Self([23, 35, 46, 59])
+    }
+}
+
+impl Display for NullValues {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let [a, b, c, d] = self.0;
+        write!(f, "{a}/{b}/{c}/{d}")
+    }
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub(crate) enum NormalMode {
     Color(Suit),
     Grand,
@@ -999,7 +1858,23 @@ pub(crate) enum NormalMode {
 impl NormalMode {
     const BITS: u32 = Suit::BITS + 1;
 
-    const fn all() -> [Self; Suit::COUNT + 1] {
+    /// Returns the lowest trump which is needed for playing _Spitze_.
+    pub(crate) const fn lowest_trump(&self) -> Card {
+        match self {
+            NormalMode::Color(suit) => Card(CardValue::Num7, *suit),
+            NormalMode::Grand => Card(CardValue::Jack, Suit::Diamonds),
+        }
+    }
+
+    /// The maximum number of (missing) matadors in this mode.
+    const fn max_matadors(&self) -> u8 {
+        match self {
+            NormalMode::Color(_) => (Suit::COUNT + CardValue::COUNT - 1) as u8,
+            NormalMode::Grand => Suit::COUNT as u8,
+        }
+    }
+
+    pub(crate) const fn all() -> [Self; Suit::COUNT + 1] {
         let mut result = [Self::Grand; Suit::COUNT + 1];
         let mut i = 0;
         while i < Suit::COUNT {
@@ -1046,16 +1921,13 @@ impl From<NormalMode> for move_code {
 }
 
 impl TryFrom<move_code> for NormalMode {
-    type Error = Error;
+    type Error = SkatError;
 
     fn try_from(value: move_code) -> std::result::Result<Self, Self::Error> {
         usize::try_from(value)
             .ok()
             .and_then(|index| Self::all().get(index).cloned())
-            .ok_or(Error::new_static(
-                ErrorCode::InvalidMove,
-                "invalid normal game mode\0",
-            ))
+            .ok_or(SkatError::InvalidNormalMode)
     }
 }
 
@@ -1071,7 +1943,8 @@ impl Display for NormalMode {
     }
 }
 
-#[derive(Clone, Copy, Debug)]
+#[derive(Clone, Copy, Debug, PartialEq, Eq, PartialOrd, Ord)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub(crate) enum GameLevel {
     Normal,
     Hand,
@@ -1093,9 +1966,23 @@ impl GameLevel {
         }
     }
 
-    fn is_hand(&self) -> bool {
+    /// Does this level require a _Hand_ game.
+    pub(crate) fn requires_hand(self) -> bool {
         !matches!(self, GameLevel::Normal)
     }
+
+    /// Does this level require announcing _Schneider_.
+    pub(crate) fn requires_schneider(self) -> bool {
+        matches!(
+            self,
+            GameLevel::Schneider | GameLevel::Schwarz | GameLevel::Ouvert
+        )
+    }
+
+    /// Does this level require announcing _Schwarz_.
+    pub(crate) fn requires_schwarz(self) -> bool {
+        matches!(self, GameLevel::Schwarz | GameLevel::Ouvert)
+    }
 }
 
 impl From<GameLevel> for u16 {
@@ -1117,7 +2004,7 @@ impl From<GameLevel> for move_code {
 }
 
 impl TryFrom<move_code> for GameLevel {
-    type Error = Error;
+    type Error = SkatError;
 
     fn try_from(value: move_code) -> std::result::Result<Self, Self::Error> {
         Ok(match value {
@@ -1126,18 +2013,16 @@ impl TryFrom<move_code> for GameLevel {
             2 => GameLevel::Schneider,
             3 => GameLevel::Schwarz,
             4 => GameLevel::Ouvert,
-            5.. => {
-                return Err(Error::new_static(
-                    ErrorCode::InvalidMove,
-                    "invalid game level\0",
-                ))
-            }
+            5.. => return Err(SkatError::InvalidGameLevel),
         })
     }
 }
 
-/// Count of the (missing) matadors per suit.
-pub(crate) struct Matadors([u8; Suit::COUNT]);
+/// Count of the (missing) matadors per suit and for Grand.
+///
+/// The Grand matadors only depend on the run of Jacks from the club Jack
+/// downward and are therefore at most four.
+pub(crate) struct Matadors([u8; Suit::COUNT], u8);
 impl Matadors {
     pub(crate) fn from_cards(cards: impl Iterator<Item = Card>) -> Self {
         let mut jacks = [false; Suit::COUNT];
@@ -1153,6 +2038,7 @@ impl Matadors {
         }
 
         let with = jacks[0];
+        let grand = jacks.iter().take_while(|&&has| has == with).count() as u8;
         let mut matadors = [0; Suit::COUNT];
         for (i, m) in matadors.iter_mut().enumerate() {
             for &has in jacks.iter().chain(colors[i].iter()) {
@@ -1163,7 +2049,7 @@ impl Matadors {
                 }
             }
         }
-        Self(matadors)
+        Self(matadors, grand)
     }
 }
 
@@ -1173,8 +2059,58 @@ impl Index<NormalMode> for Matadors {
     fn index(&self, index: NormalMode) -> &Self::Output {
         match index {
             NormalMode::Color(suit) => &self.0[suit as usize],
-            NormalMode::Grand => self.0.iter().min().unwrap().min(&(Suit::COUNT as u8)),
+            NormalMode::Grand => &self.1,
+        }
+    }
+}
+
+/// Simple strength indicators of a hand for bidding bots.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub(crate) struct HandMetrics {
+    pub jacks: u8,
+    pub aces: u8,
+    pub tens: u8,
+    /// Number of trumps per suit if it were the trump suit, i.e., the jacks
+    /// plus the cards of this suit.
+    pub trump_length: [u8; Suit::COUNT],
+    /// Rough count of tricks which cannot be lost.
+    ///
+    /// This is the run of jacks from the club Jack downward plus every ace
+    /// and every ten backed by the ace of its suit.
+    pub sure_tricks: u8,
+}
+
+impl HandMetrics {
+    pub(crate) fn from_cards(cards: impl Iterator<Item = Card>) -> Self {
+        let mut metrics = Self::default();
+        let mut jacks = [false; Suit::COUNT];
+        let mut aces = [false; Suit::COUNT];
+        let mut tens = [false; Suit::COUNT];
+
+        for Card(value, suit) in cards {
+            let idx = suit as usize;
+            match value {
+                CardValue::Jack => jacks[idx] = true,
+                CardValue::Ace => aces[idx] = true,
+                CardValue::Num10 => tens[idx] = true,
+                _ => {}
+            }
+            if !matches!(value, CardValue::Jack) {
+                metrics.trump_length[idx] += 1;
+            }
+        }
+
+        let count = |has: &[bool]| has.iter().filter(|&&h| h).count() as u8;
+        metrics.jacks = count(&jacks);
+        metrics.aces = count(&aces);
+        metrics.tens = count(&tens);
+        for length in &mut metrics.trump_length {
+            *length += metrics.jacks;
         }
+        let backed_tens = aces.iter().zip(&tens).filter(|(&a, &t)| a && t).count() as u8;
+        metrics.sure_tricks =
+            jacks.iter().take_while(|&&h| h).count() as u8 + metrics.aces + backed_tens;
+        metrics
     }
 }
 
@@ -1182,22 +2118,28 @@ impl Index<NormalMode> for Matadors {
 pub(crate) enum DeclarationMove {
     Declare(Declaration),
     Overbidden,
+    /// Announce winning the last trick with the lowest trump.
+    ///
+    /// This is made before the actual declaration.
+    Spitze,
 }
 
 impl DeclarationMove {
     const OVERBIDDEN: move_code = 1 << Declaration::BITS;
+    const SPITZE: move_code = Self::OVERBIDDEN + 1;
 
     /// Parse a declaration move from string.
     ///
     /// # Examples
     /// These moves can be parsed: `cLubs`, `null  Ouvert hand`,
-    /// `grand sChWaRz`, `overbidden`.
+    /// `grand sChWaRz`, `overbidden`, `spitze`.
     /// However, these do not parse: `null hand ouvert`, `grand offen`.
     pub(crate) fn parse(input: &str) -> IResult<&str, Self> {
         context(
             "declaration move",
             alt((
                 value(Self::Overbidden, tag_no_case("overbidden")),
+                value(Self::Spitze, tag_no_case("spitze")),
                 map(Declaration::parse, Self::Declare),
             )),
         )(input)
@@ -1212,6 +2154,7 @@ impl From<DeclarationMove> for move_code {
         match value {
             DeclarationMove::Declare(d) => d.into(),
             DeclarationMove::Overbidden => DeclarationMove::OVERBIDDEN,
+            DeclarationMove::Spitze => DeclarationMove::SPITZE,
         }
     }
 }
@@ -1223,30 +2166,26 @@ impl From<DeclarationMove> for MoveCode {
 }
 
 impl TryFrom<move_code> for DeclarationMove {
-    type Error = Error;
+    type Error = SkatError;
 
     fn try_from(value: move_code) -> std::result::Result<Self, Self::Error> {
         Ok(match value {
             Self::OVERBIDDEN => Self::Overbidden,
+            Self::SPITZE => Self::Spitze,
             _ => Self::Declare(value.try_into()?),
         })
     }
 }
 
 impl FromStr for DeclarationMove {
-    type Err = Error;
+    type Err = SkatError;
 
     /// Parses into a [`Self`] like [`Self::parse()`] but with trimming.
     fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
         Ok(
             terminated(delimited(space0, DeclarationMove::parse, space0), eof)(s)
                 .finish()
-                .map_err(|e| {
-                    Error::new_dynamic(
-                        ErrorCode::InvalidInput,
-                        format!("failed to parse declaration:\n{}", convert_error(s, e)),
-                    )
-                })?
+                .map_err(|e| SkatError::Parse("declaration", convert_error(s, e)))?
                 .1,
         )
     }
@@ -1257,10 +2196,77 @@ impl Display for DeclarationMove {
         match self {
             DeclarationMove::Declare(declaration) => declaration.fmt(f),
             DeclarationMove::Overbidden => write!(f, "overbidden"),
+            DeclarationMove::Spitze => write!(f, "Spitze"),
         }
     }
 }
 
+/// A set of [`Card`]s stored as a bitmask indexed by [`Card::index()`].
+#[derive(Clone, Copy, PartialEq, Eq, Debug, Default)]
+pub(crate) struct CardSet(u32);
+
+impl CardSet {
+    pub(crate) fn contains(&self, card: Card) -> bool {
+        self.0 & (1 << card.index()) != 0
+    }
+
+    pub(crate) fn insert(&mut self, card: Card) {
+        #[allow(clippy::assertions_on_constants)]
+        const _: () = assert!(Card::COUNT <= u32::BITS as usize);
+
+        self.0 |= 1 << card.index();
+    }
+
+    pub(crate) fn is_empty(&self) -> bool {
+        self.0 == 0
+    }
+
+    #[cfg_attr(not(test), allow(dead_code))]
+    pub(crate) fn len(&self) -> usize {
+        self.0.count_ones() as usize
+    }
+
+    pub(crate) fn intersection(self, other: Self) -> Self {
+        Self(self.0 & other.0)
+    }
+
+    pub(crate) fn difference(self, other: Self) -> Self {
+        Self(self.0 & !other.0)
+    }
+
+    #[cfg_attr(not(test), allow(dead_code))]
+    pub(crate) fn iter(&self) -> impl Iterator<Item = Card> + '_ {
+        Card::all().into_iter().filter(|&c| self.contains(c))
+    }
+
+    /// Returns the cards of `self` which are trumps under the `declaration`.
+    ///
+    /// These are the jacks in a Grand game, the jacks and the cards of the
+    /// trump suit in a color game, and no cards in a Null game.
+    pub(crate) fn trumps_under(self, declaration: Declaration) -> Self {
+        let trumps: Self = Card::all()
+            .into_iter()
+            .filter(|c| matches!(c.trump_suit(declaration), TrumpSuit::Trump))
+            .collect();
+        self.intersection(trumps)
+    }
+
+    /// Returns the cards of `self` of the `suit` including any trumps.
+    pub(crate) fn of_suit(self, suit: Suit) -> Self {
+        self.intersection(Card::deck_by_suit()[suit as usize].into_iter().collect())
+    }
+}
+
+impl FromIterator<Card> for CardSet {
+    fn from_iter<T: IntoIterator<Item = Card>>(iter: T) -> Self {
+        let mut set = Self::default();
+        for card in iter {
+            set.insert(card);
+        }
+        set
+    }
+}
+
 /// Suit of a card including trump cards.
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
 pub(crate) enum TrumpSuit {
@@ -1268,6 +2274,17 @@ pub(crate) enum TrumpSuit {
     Trump,
 }
 
+/// Returns the index of the card winning the `trick`.
+fn trick_winner(trick: &[Card], declaration: Declaration) -> usize {
+    let mut w = 0;
+    for (c, curr) in trick.iter().enumerate().skip(1) {
+        if curr.beats(&trick[w], trick[0], declaration) {
+            w = c;
+        }
+    }
+    w
+}
+
 /// Returns the number of bits required to represent `count` states.
 ///
 /// # Panics
@@ -1283,3 +2300,390 @@ const fn max(a: u32, b: u32) -> u32 {
         a
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn card(s: &str) -> Card {
+        s.parse().unwrap()
+    }
+
+    #[test]
+    fn player_from_id() {
+        for player in Player::all() {
+            assert_eq!(Player::try_from(player_id::from(player)), Ok(player));
+        }
+        for id in [PLAYER_NONE, PLAYER_RAND, 4] {
+            assert_eq!(Player::try_from(id), Err(SkatError::NoSeat));
+        }
+    }
+
+    #[test]
+    fn beats() {
+        let grand = Declaration::Normal(NormalMode::Grand, GameLevel::Normal);
+        let hearts = Declaration::Normal(NormalMode::Color(Suit::Hearts), GameLevel::Normal);
+        let led = card("7H");
+        // Neither follows suit nor is a trump.
+        assert!(!card("AS").beats(&card("10S"), led, grand));
+        assert!(!card("10S").beats(&card("AS"), led, grand));
+        assert!(!card("AS").beats(&card("7H"), led, grand));
+        // Following suit beats discards and lower cards of the led suit.
+        assert!(card("8H").beats(&card("AS"), led, grand));
+        assert!(card("AH").beats(&card("10H"), led, grand));
+        assert!(!card("10H").beats(&card("AH"), led, grand));
+        // Trumps beat everything else.
+        assert!(card("JD").beats(&card("AH"), led, grand));
+        assert!(!card("AH").beats(&card("JD"), led, grand));
+        assert!(card("JC").beats(&card("JS"), led, grand));
+        assert!(card("7H").beats(&card("AS"), card("AS"), hearts));
+        assert!(!card("AS").beats(&card("7H"), card("AS"), hearts));
+        // In Null games, the ten ranks between the nine and the Jack.
+        assert!(card("JH").beats(&card("10H"), led, Declaration::Null));
+        assert!(!card("AS").beats(&card("7H"), led, Declaration::Null));
+    }
+
+    #[test]
+    fn points_remaining() {
+        let cards = CardStruct {
+            skat: CardVec::from(&[card("10H"), card("QH")][..]),
+            trick: vec![card("AS")],
+            played: [vec![card("10S")], vec![], vec![]],
+            ..Default::default()
+        };
+        let grand = Declaration::Normal(NormalMode::Grand, GameLevel::Normal);
+        assert_eq!(cards.points_remaining(grand), 120 - 10 - 11 - 13);
+        assert_eq!(cards.points_remaining(Declaration::Ramsch), 120 - 10 - 11);
+    }
+
+    #[test]
+    fn allowed_cards() {
+        let mut cards = CardStruct::default();
+        cards.hands[1] = CardVec::from(&[card("JC"), card("AS"), card("7H"), card("10D")][..]);
+        let spades = Declaration::Normal(NormalMode::Color(Suit::Spades), GameLevel::Normal);
+        let grand = Declaration::Normal(NormalMode::Grand, GameLevel::Normal);
+        let allowed =
+            |cards: &CardStruct, declaration| cards.allowed(Player::Middlehand, declaration);
+
+        cards.trick = vec![card("KS")];
+        // The Jack of clubs is a trump and not a spade.
+        assert_eq!(allowed(&cards, grand), [card("AS")]);
+        assert_eq!(allowed(&cards, spades), [card("JC"), card("AS")]);
+        assert_eq!(allowed(&cards, Declaration::Null), [card("AS")]);
+
+        cards.trick = vec![card("JD")];
+        assert_eq!(allowed(&cards, grand), [card("JC")]);
+        // The Jack of diamonds is a plain diamond in Null games.
+        assert_eq!(allowed(&cards, Declaration::Null), [card("10D")]);
+
+        cards.trick = vec![card("AC")];
+        assert_eq!(allowed(&cards, grand).len(), 4);
+        assert_eq!(allowed(&cards, Declaration::Null), [card("JC")]);
+    }
+
+    #[test]
+    fn parse_card_detailed() {
+        assert_eq!(super::parse_card_detailed(" JC "), Ok(card("JC")));
+        let error = super::parse_card_detailed("10X").unwrap_err();
+        assert_eq!(error.offset, 2);
+        assert_eq!(error.context, ["suit", "card"]);
+        assert_eq!(
+            error.to_string(),
+            "parsing failed at byte 2 in suit in card"
+        );
+    }
+
+    #[test]
+    fn consistent_with_visible() {
+        let mut cards = CardStruct::default();
+        cards.give(Some(Player::Forehand), OptCard::Hidden);
+        cards.give(Some(Player::Middlehand), card("AS").into());
+        cards.trick = vec![card("10S")];
+        assert_eq!(
+            cards.location_of(card("AS")),
+            Some(Location::Hand(Player::Middlehand))
+        );
+        assert_eq!(cards.location_of(card("10S")), Some(Location::Trick));
+        assert_eq!(cards.location_of(card("7C")), None);
+
+        let consistent = |known: &[(&str, Location)]| {
+            let known = known.iter().map(|&(c, l)| (card(c), l)).collect();
+            cards.consistent_with_visible(&known)
+        };
+        assert!(consistent(&[("AS", Location::Hand(Player::Middlehand))]));
+        assert!(!consistent(&[("AS", Location::Skat)]));
+        // Forehand holds a single hidden card.
+        assert!(consistent(&[("7C", Location::Hand(Player::Forehand))]));
+        assert!(!consistent(&[
+            ("7C", Location::Hand(Player::Forehand)),
+            ("8C", Location::Hand(Player::Forehand)),
+        ]));
+        assert!(!consistent(&[("7C", Location::Skat)]));
+        assert!(!consistent(&[("7C", Location::Played(Player::Forehand))]));
+    }
+
+    #[test]
+    fn transfer_card() {
+        let mut cards = CardStruct::default();
+        cards.give(Some(Player::Forehand), card("AS").into());
+        cards
+            .transfer_card(Player::Forehand, Player::Rearhand, card("AS"))
+            .unwrap();
+        assert!(cards[Player::Forehand].is_empty());
+        assert_eq!(cards[Player::Rearhand][..], [card("AS").into()]);
+        assert_eq!(
+            cards.transfer_card(Player::Forehand, Player::Middlehand, card("AS")),
+            Err(SkatError::CannotTake)
+        );
+        assert!(cards[Player::Middlehand].is_empty());
+    }
+
+    #[test]
+    fn deal_from_seed() {
+        for seed in [0, 1, 42] {
+            let cards = CardStruct::deal_from_seed(seed);
+            assert!(cards.hands.iter().all(|h| h.len() == CardStruct::HAND_SIZE));
+            assert_eq!(cards.skat.len(), CardStruct::SKAT_SIZE);
+            let set: CardSet = cards.iter().collect();
+            assert_eq!(set.len(), Card::COUNT);
+            assert_eq!(
+                cards.hands,
+                CardStruct::deal_from_seed(seed).hands,
+                "dealing is not reproducible"
+            );
+        }
+        assert_ne!(
+            CardStruct::deal_from_seed(1).hands,
+            CardStruct::deal_from_seed(2).hands
+        );
+    }
+
+    #[test]
+    fn iter_all_positions() {
+        let mut cards = CardStruct::default();
+        cards.give(Some(Player::Middlehand), card("AS").into());
+        cards.give(Some(Player::Middlehand), OptCard::Hidden);
+        cards.give(None, card("7C").into());
+        cards.trick = vec![card("10H")];
+        cards.played[0] = vec![card("JC")];
+        let positions: Vec<_> = cards.iter_all_positions().collect();
+        assert_eq!(
+            positions,
+            [
+                (CardLocation::Hand(Player::Middlehand, 0), card("AS").into()),
+                (CardLocation::Hand(Player::Middlehand, 1), OptCard::Hidden),
+                (CardLocation::Skat(0), card("7C").into()),
+                (CardLocation::Trick(0), card("10H").into()),
+                (CardLocation::Played(Player::Forehand, 0), card("JC").into()),
+            ]
+        );
+    }
+
+    #[test]
+    fn highest_and_lowest_card_for_game() {
+        let hand = CardVec::from(&[card("7H"), card("QH"), card("JD"), card("10H")][..]);
+        let grand = Declaration::Normal(NormalMode::Grand, GameLevel::Normal);
+        let hearts = Declaration::Normal(NormalMode::Color(Suit::Hearts), GameLevel::Normal);
+        for declaration in [grand, hearts] {
+            assert_eq!(hand.highest_card_for_game(declaration), Some(card("JD")));
+            assert_eq!(hand.lowest_card_for_game(declaration), Some(card("7H")));
+        }
+        // The Jack ranks between the ten and the queen in Null games.
+        let hand = CardVec::from(&[card("JH"), card("7H"), card("QH"), card("10H")][..]);
+        assert_eq!(
+            hand.highest_card_for_game(Declaration::Null),
+            Some(card("QH"))
+        );
+        assert_eq!(
+            hand.lowest_card_for_game(Declaration::Null),
+            Some(card("7H"))
+        );
+        assert_eq!(CardVec::default().highest_card_for_game(grand), None);
+    }
+
+    #[test]
+    fn trumps_under() {
+        let hand: CardSet = ["JC", "JD", "AH", "7H", "AS"]
+            .map(card)
+            .into_iter()
+            .collect();
+        let grand = Declaration::Normal(NormalMode::Grand, GameLevel::Normal);
+        let hearts = Declaration::Normal(NormalMode::Color(Suit::Hearts), GameLevel::Normal);
+        let trumps: Vec<_> = hand.trumps_under(grand).iter().collect();
+        assert_eq!(trumps, [card("JC"), card("JD")]);
+        assert_eq!(hand.trumps_under(hearts).len(), 4);
+        assert!(hand.trumps_under(Declaration::Null).is_empty());
+    }
+
+    #[test]
+    fn parse_ten_as_t() {
+        assert_eq!(card("TS"), card("10S"));
+        assert_eq!(card("Th"), card("10H"));
+        assert_eq!(card("tC").to_string(), "10C");
+        // `T` is never mistaken for a suit.
+        let mixed: Vec<Card> = "TS 10S JT th 10d"
+            .split_whitespace()
+            .filter_map(|c| c.parse().ok())
+            .collect();
+        assert_eq!(mixed, [card("10S"), card("10S"), card("10H"), card("10D")]);
+    }
+
+    #[test]
+    fn deck_by_suit() {
+        let deck = Card::deck_by_suit();
+        let set: CardSet = deck.iter().flatten().copied().collect();
+        assert_eq!(set, Card::all().into_iter().collect());
+        assert_eq!(deck.iter().flatten().count(), Card::COUNT);
+        for (cards, suit) in deck.iter().zip(Suit::all()) {
+            assert!(cards.iter().all(|c| c.1 == suit));
+        }
+        assert_eq!(deck[0][0], card("7C"));
+        assert_eq!(deck[3][7], card("AD"));
+    }
+
+    #[test]
+    fn sort_for_declaration() {
+        let mut cards = CardStruct::default();
+        for c in ["10H", "JD", "AH", "?", "7H"] {
+            cards.give(Some(Player::Forehand), c.parse().unwrap());
+        }
+        cards.give(None, card("JH").into());
+        cards.give(None, card("QH").into());
+
+        let mut null = cards.clone();
+        null.sort_for_declaration(Declaration::Null);
+        let hand: Vec<_> = null[Player::Forehand]
+            .iter()
+            .map(|c| c.to_string())
+            .collect();
+        assert_eq!(hand, ["AH", "10H", "7H", "JD", "?"]);
+        assert_eq!(null.skat[0], card("QH").into());
+
+        cards.sort_for_declaration(Declaration::Normal(NormalMode::Grand, GameLevel::Normal));
+        let hand: Vec<_> = cards[Player::Forehand]
+            .iter()
+            .map(|c| c.to_string())
+            .collect();
+        assert_eq!(hand, ["JD", "AH", "10H", "7H", "?"]);
+        assert_eq!(cards.skat[0], card("JH").into());
+    }
+
+    #[test]
+    fn card_counts() {
+        let mut cards = CardStruct::default();
+        for (dealt, card) in Card::all().into_iter().enumerate() {
+            cards.give(crate::deal_to(dealt as u8).unwrap(), card.into());
+        }
+        assert_eq!(cards.hidden_card_count(), 0);
+        assert_eq!(cards.open_card_count(), Card::COUNT);
+        cards.redact([true, false, false]);
+        assert_eq!(cards.hidden_card_count(), 22);
+        assert_eq!(cards.open_card_count(), CardStruct::HAND_SIZE);
+    }
+
+    #[test]
+    fn current_trick_leader() {
+        let mut cards = CardStruct::default();
+        let grand = Declaration::Normal(NormalMode::Grand, GameLevel::Normal);
+        assert_eq!(cards.current_trick_leader(Player::Forehand, grand), None);
+        // Middlehand led and forehand is to play next.
+        cards.trick = vec![card("10S"), card("AS")];
+        assert_eq!(
+            cards.current_trick_leader(Player::Forehand, grand),
+            Some((1, Player::Rearhand))
+        );
+        // Forehand led the complete trick and is to play next.
+        cards.trick.push(card("JD"));
+        assert_eq!(
+            cards.current_trick_leader(Player::Forehand, grand),
+            Some((2, Player::Rearhand))
+        );
+    }
+
+    #[test]
+    fn level_hierarchy() {
+        for level in [
+            GameLevel::Normal,
+            GameLevel::Hand,
+            GameLevel::Schneider,
+            GameLevel::Schwarz,
+            GameLevel::Ouvert,
+        ] {
+            assert!(!level.requires_schwarz() || level.requires_schneider());
+            assert!(!level.requires_schneider() || level.requires_hand());
+        }
+        assert!(!GameLevel::Normal.requires_hand());
+        assert!(GameLevel::Hand.requires_hand() && !GameLevel::Hand.requires_schneider());
+        assert!(
+            GameLevel::Schneider.requires_schneider() && !GameLevel::Schneider.requires_schwarz()
+        );
+        assert!(GameLevel::Ouvert.requires_schwarz());
+    }
+
+    #[test]
+    fn custom_null_values() {
+        let null = terminated(NullValues::parse, eof)("8/30/40/264")
+            .finish()
+            .unwrap()
+            .1;
+        assert_eq!(Declaration::Null.value(&null), 8);
+        assert_eq!(Declaration::NullOuvertHand.value(&null), 264);
+        assert!(Declaration::is_game_value(30, &null));
+        assert!(!Declaration::is_game_value(23, &null));
+
+        // Null now ranks below Diamonds (9) unlike with the standard values.
+        let diamonds = Declaration::Normal(NormalMode::Color(Suit::Diamonds), GameLevel::Normal);
+        assert!(Declaration::Null.sort_key(&null) < diamonds.sort_key(&null));
+        assert!(Declaration::Null > diamonds);
+        assert!(
+            Declaration::NullOuvertHand.sort_key(&null) > Declaration::NullOuvert.sort_key(&null)
+        );
+    }
+
+    #[test]
+    fn invalid_null_values() {
+        for raw in ["0/35/46/59", "23/35/46/265", "23/35/46/65535", "23/35/46"] {
+            assert!(
+                terminated(NullValues::parse, eof)(raw).finish().is_err(),
+                "{raw} accepted"
+            );
+        }
+    }
+
+    #[test]
+    fn declaration_round_trip() {
+        let mut codes = vec![];
+        for declaration in Declaration::all(false)
+            .into_iter()
+            .chain(Declaration::all(true))
+            .chain([Declaration::Ramsch])
+        {
+            let md = move_code::from(declaration);
+            assert_eq!(Declaration::try_from(md), Ok(declaration));
+            if matches!(declaration, Declaration::Normal(_, _)) {
+                assert!(
+                    md > Declaration::RAMSCH,
+                    "{declaration} collides with a sentinel"
+                );
+            }
+            codes.push(md);
+
+            let parsed = declaration.to_string().parse::<DeclarationMove>();
+            assert_eq!(parsed.map(move_code::from), Ok(md));
+        }
+        let count = codes.len();
+        codes.sort();
+        codes.dedup();
+        assert_eq!(codes.len(), count);
+        assert!(Declaration::try_from(1 << Declaration::BITS).is_err());
+    }
+
+    #[test]
+    fn declaration_order_matches_default_null_values() {
+        let null = NullValues::default();
+        let mut declarations = Declaration::all(true);
+        declarations.sort();
+        let keys: Vec<_> = declarations.iter().map(|d| d.sort_key(&null)).collect();
+        assert!(keys.windows(2).all(|w| w[0] <= w[1]));
+    }
+}